@@ -0,0 +1,145 @@
+//! Compares the compressed guide content against the plain strings it replaced.
+//! Run with `cargo make bench`.
+//!
+//! For each of `book::SECTIONS`:
+//! - Binary size: the bytes embedded; compressed, vs the uncompressed pandoc
+//!   output the last commit with plain strings embedded.
+//! - Time-to-first-render: the work done before its HTML can be handed to
+//!   `raw!`; decompressing it, vs converting a `&'static str` to a `String`.
+//!
+//! Then the release `.wasm` files' sizes, built from this tree and from that
+//! commit, which is checked out in a worktree beside this one, so `../seed`
+//! resolves for it too.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+#[path = "../src/book/mod.rs"]
+#[allow(dead_code)]
+mod book;
+
+const ITERATIONS: u32 = 1_000;
+/// Added by the commit that compressed the content; its parent is the last
+/// with plain strings.
+const COMPRESSED_MARKER: &str = "src/book/view.html.z";
+const WASM: &str = "target/wasm32-unknown-unknown/release/seed_homepage.wasm";
+
+fn time<T>(f: impl Fn() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(f());
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).current_dir(root()).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// The last commit that embedded sections as plain strings.
+fn plain_strings_commit() -> String {
+    let log = ["log", "--diff-filter=A", "--reverse", "--format=%H", "--", COMPRESSED_MARKER];
+    let added = git(&log)
+        .and_then(|log| log.lines().next().map(String::from))
+        .unwrap_or_else(|| panic!("No commit added {}", COMPRESSED_MARKER));
+    format!("{}^", added)
+}
+
+/// A section's HTML as that commit embedded it, in a raw string literal; `None`
+/// for sections added since.
+fn previous_html(commit: &str, file: &str) -> Option<String> {
+    let source = git(&["show", &format!("{}:src/book/{}.rs", commit, file)])?;
+    let start = source.find("r#####\"")? + "r#####\"".len();
+    let end = source.rfind("\"#####")?;
+    Some(source[start..end].trim_start_matches('\n').to_string())
+}
+
+/// Build the release wasm in `dir`, and return its size.
+fn wasm_size(dir: &Path) -> u64 {
+    let status = Command::new("cargo")
+        .args(["build", "--release", "--lib", "--target", "wasm32-unknown-unknown"])
+        .current_dir(dir)
+        .status()
+        .expect("Problem running cargo");
+    assert!(status.success(), "Problem building the wasm in {:?}", dir);
+
+    let wasm = dir.join(WASM);
+    fs::metadata(&wasm)
+        .unwrap_or_else(|e| panic!("Problem reading {:?}: {}", wasm, e))
+        .len()
+}
+
+fn before_worktree(commit: &str) -> PathBuf {
+    let name = root().file_name().unwrap_or_default().to_string_lossy();
+    let dir = root().with_file_name(format!("{}-plain-strings", name));
+    let path = dir.to_string_lossy();
+    git(&["worktree", "add", "--detach", &path, commit])
+        .unwrap_or_else(|| panic!("Problem checking out {} in {}", commit, path));
+    dir
+}
+
+fn main() {
+    let commit = plain_strings_commit();
+
+    println!(
+        "{:<24}{:>10}{:>12}{:>8}{:>14}{:>14}",
+        "section", "plain (B)", "compr. (B)", "ratio", "plain (µs)", "compr. (µs)"
+    );
+
+    let (mut plain_total, mut compressed_total) = (0, 0);
+    for section in book::SECTIONS {
+        let compressed_path = root().join(format!("src/book/{}.html.z", section.file));
+        let compressed = fs::read(&compressed_path)
+            .unwrap_or_else(|e| panic!("Problem reading {:?}: {}", compressed_path, e));
+        let plain: &'static str = match previous_html(&commit, section.file) {
+            Some(html) => Box::leak(html.into_boxed_str()),
+            None => {
+                println!("{:<24}{:>10}{:>12}", section.slug, "-", compressed.len());
+                continue;
+            }
+        };
+
+        let plain_time = time(|| String::from(plain));
+        let compressed_time = time(|| (section.text)(book::languages::DEFAULT_LANGUAGE));
+
+        println!(
+            "{:<24}{:>10}{:>12}{:>8.2}{:>14.1}{:>14.1}",
+            section.slug,
+            plain.len(),
+            compressed.len(),
+            compressed.len() as f64 / plain.len() as f64,
+            plain_time.as_secs_f64() * 1e6,
+            compressed_time.as_secs_f64() * 1e6,
+        );
+
+        plain_total += plain.len();
+        compressed_total += compressed.len();
+    }
+
+    println!(
+        "{:<24}{:>10}{:>12}{:>8.2}",
+        "total",
+        plain_total,
+        compressed_total,
+        compressed_total as f64 / plain_total as f64
+    );
+
+    let after = wasm_size(root());
+    let before_dir = before_worktree(&commit);
+    let before = wasm_size(&before_dir);
+    git(&["worktree", "remove", "--force", &before_dir.to_string_lossy()]);
+
+    println!();
+    println!("{:<24}{:>10}{:>12}{:>8}", "release wasm", "before (B)", "after (B)", "ratio");
+    println!("{:<24}{:>10}{:>12}{:>8.2}", "", before, after, after as f64 / before as f64);
+}
@@ -0,0 +1,56 @@
+# Rewrite the view snapshots in `tests/snapshots/` that differ from what the
+# app renders now; see `src/snapshots.rs`. Review the diff before committing.
+# Usage: python bless_snapshots.py firefox  (or chrome, safari)
+
+import os
+import subprocess
+import sys
+
+SNAPSHOT_DIR = "tests/snapshots"
+# Printed around each differing snapshot by the test.
+BEGIN = "--- snapshot begin: "
+END = "--- snapshot end"
+
+
+def snapshots(output):
+    """(name, html) for each snapshot in the test output. The runner may indent
+    logged lines, so we strip whatever came before the begin marker."""
+    name, indent, lines = None, "", []
+    for line in output.splitlines():
+        if name is None:
+            if BEGIN in line:
+                indent = line[:line.index(BEGIN)]
+                name = line[line.index(BEGIN) + len(BEGIN):].strip()
+                lines = []
+        elif line.strip() == END:
+            yield name, "".join(l + "\n" for l in lines)
+            name = None
+        else:
+            lines.append(line[len(indent):] if line.startswith(indent) else line)
+
+
+def main():
+    browser = sys.argv[1] if len(sys.argv) > 1 else "firefox"
+    result = subprocess.run(
+        ["wasm-pack", "test", "--headless", "--" + browser],
+        stdout=subprocess.PIPE,
+        stderr=subprocess.STDOUT,
+        universal_newlines=True,
+    )
+
+    blessed = 0
+    for name, html in snapshots(result.stdout):
+        with open(os.path.join(SNAPSHOT_DIR, name + ".html"), "w", encoding="utf-8") as f:
+            f.write(html)
+        print("Blessed", name)
+        blessed += 1
+
+    if result.returncode != 0 and blessed == 0:
+        print(result.stdout)
+        sys.exit("Tests failed, and not because of snapshots.")
+    if blessed == 0:
+        print("Snapshots are up to date.")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,541 @@
+//! Reads the version, changelog, public API items and examples of the Seed we build
+//! against, so the homepage can't drift from them. `seed` is a path dependency;
+//! without it, eg for a standalone build, we use the copy of its changelog in
+//! `vendor/`, and don't link to its API docs or list its examples.
+//!
+//! Also checks and embeds the projects in `showcase.toml`, and collects the
+//! guide's Rust examples for `tests/guide_examples.rs` to compile.
+
+use std::collections::HashSet;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[path = "build/showcase.rs"]
+mod showcase;
+
+use showcase::{check_project, parse_showcase, SHOWCASE_SCREENSHOTS};
+
+const SEED_MANIFEST: &str = "../seed/Cargo.toml";
+const SEED_SRC: &str = "../seed/src";
+const SEED_CHANGELOG: &str = "../seed/CHANGELOG.md";
+const VENDORED_CHANGELOG: &str = "vendor/seed/CHANGELOG.md";
+const SEED_EXAMPLES: &str = "../seed/examples";
+/// Prebuilt examples we host, each with an `index.html`, eg `public/examples/todomvc/`.
+const EXAMPLE_DEMOS: &str = "public/examples";
+
+const SHOWCASE: &str = "showcase.toml";
+
+/// Guide chapters; their ```rust blocks are compiled as tests.
+const GUIDE_MARKDOWN: &str = "markdown";
+
+/// Features an example's card lists, and text in its source that shows it uses them.
+const EXAMPLE_FEATURES: &[(&str, &[&str])] = &[
+    ("Routing", &[".routes("]),
+    ("Fetch", &["fetch::", "Request::new"]),
+    ("Local storage", &["storage::"]),
+    ("Window events", &[".window_events("]),
+    ("Raw events", &["raw_ev("]),
+    ("Canvas", &["canvas!", "HtmlCanvasElement"]),
+    ("SVG", &["svg!"]),
+    ("Markdown", &["md!"]),
+    ("Animation", &["request_animation_frame"]),
+    ("Components", &[".map_message(", ".map_msg("]),
+];
+
+/// A key in the `[package]` table, like `version`; dependency versions come later.
+fn package_value(manifest: &str, name: &str) -> Option<String> {
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[package]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter_map(|line| {
+            let (key, value) = line.split_at(line.find('=')?);
+            if key.trim() == name {
+                Some(value[1..].trim().trim_matches('"').to_string())
+            } else {
+                None
+            }
+        })
+        .next()
+}
+
+/// The newest release in a changelog, from headings like `## v0.4.1`.
+fn changelog_version(changelog: &str) -> Option<String> {
+    changelog
+        .lines()
+        .filter(|line| line.starts_with("## "))
+        .map(|line| line[3..].trim().trim_start_matches(&['v', 'V'][..]))
+        .find(|heading| heading.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|heading| heading.split_whitespace().next().unwrap_or(heading).to_string())
+}
+
+/// A Rust identifier at the start of `s`.
+fn ident(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Whether `parent` declares `pub mod name`.
+fn is_pub_mod(parent: &str, name: &str) -> bool {
+    parent.lines().any(|line| {
+        let line = line.trim();
+        line.starts_with("pub mod ") && ident(&line["pub mod ".len()..]) == name
+    })
+}
+
+/// What the crate root re-exports with `pub use`: every name its statements
+/// mention, and the modules it glob-imports, as in `pub use crate::{app::App,
+/// routing::*}`. Over-inclusive for path segments, which doesn't matter here.
+struct Reexports {
+    names: HashSet<String>,
+    globs: HashSet<String>,
+}
+
+fn reexports(lib: &str) -> Reexports {
+    let mut statements = String::new();
+    let mut in_use = false;
+    for line in lib.lines() {
+        in_use = in_use || line.starts_with("pub use ");
+        if in_use {
+            statements.push_str(line);
+            statements.push(' ');
+            in_use = !line.trim_end().ends_with(';');
+        }
+    }
+
+    let mut names = HashSet::new();
+    let mut globs = HashSet::new();
+    let mut last = String::new();
+    for word in statements.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '*')) {
+        if word == "*" {
+            globs.insert(last.clone());
+        } else if word == "as" {
+            // Renamed; documented under the new name.
+            names.remove(&last);
+        } else if !word.is_empty() {
+            names.insert(word.to_string());
+            last = word.to_string();
+        }
+    }
+    Reexports { names, globs }
+}
+
+/// Where a module's items are documented: under its path, for public modules
+/// like `dom_types`, or for a private one, at the crate root if it's
+/// re-exported there. Items that aren't are left out, rather than linked to a
+/// page that doesn't exist.
+#[derive(Clone, Copy)]
+enum Docs<'a> {
+    Module(&'a [String]),
+    Private(&'a str, &'a Reexports),
+}
+
+impl Docs<'_> {
+    /// The directory of the module's pages, under the crate's docs root.
+    fn dir(&self) -> String {
+        match self {
+            Docs::Module(module) if !module.is_empty() => module.join("/") + "/",
+            _ => String::new(),
+        }
+    }
+
+    fn documents(&self, name: &str) -> bool {
+        match self {
+            Docs::Module(_) => true,
+            Docs::Private(module, root) => {
+                root.names.contains(name) || root.globs.contains(*module)
+            }
+        }
+    }
+}
+
+/// Public items in a source file, as (name, docs.rs path).
+fn file_items(file: &Path, docs: Docs, items: &mut Vec<(String, String)>) {
+    let source = match fs::read_to_string(file) {
+        Ok(source) => source,
+        Err(_) => return,
+    };
+    let dir = docs.dir();
+
+    let mut macro_export = false;
+    // Only top-level items; not methods in `impl` blocks.
+    for line in source.lines() {
+        // Exported macros are always documented at the crate root.
+        if line.starts_with("macro_rules! ") && macro_export {
+            let name = ident(&line["macro_rules! ".len()..]);
+            items.push((format!("{}!", name), format!("macro.{}.html", name)));
+        }
+        if line.starts_with("#[macro_export]") {
+            macro_export = true;
+        } else if !line.starts_with("#[") {
+            macro_export = false;
+        }
+
+        for kind in &["struct", "enum", "trait", "fn", "type"] {
+            let prefix = format!("pub {} ", kind);
+            if line.starts_with(&prefix) {
+                let name = ident(&line[prefix.len()..]);
+                if docs.documents(name) {
+                    items.push((name.into(), format!("{}{}.{}.html", dir, kind, name)));
+                }
+            }
+        }
+    }
+}
+
+/// Walk the modules declared in `source`, which is the file for `module`.
+fn collect_items(
+    dir: &Path,
+    source: &str,
+    docs: Docs,
+    root: &Reexports,
+    items: &mut Vec<(String, String)>,
+) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        if name == "lib" || name == "mod" {
+            continue;
+        }
+
+        let child_path = match docs {
+            Docs::Module(module) if is_pub_mod(source, &name) => {
+                Some([module, std::slice::from_ref(&name)].concat())
+            }
+            _ => None,
+        };
+        let child = match (&child_path, docs) {
+            (Some(path), _) => Docs::Module(path),
+            // Below a private module, what's re-exported depends on the top one.
+            (None, Docs::Private(..)) => docs,
+            (None, Docs::Module(_)) => Docs::Private(&name, root),
+        };
+
+        if path.is_dir() {
+            // Eg `app/mod.rs`, or `app.rs` beside `app/`, which we read below.
+            let mod_file = path.join("mod.rs");
+            file_items(&mod_file, child, items);
+            let sibling = path.with_extension("rs");
+            let mod_source = fs::read_to_string(&mod_file)
+                .or_else(|_| fs::read_to_string(&sibling))
+                .unwrap_or_default();
+            collect_items(&path, &mod_source, child, root, items);
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            file_items(&path, child, items);
+        }
+    }
+}
+
+fn api_items() -> Vec<(String, String)> {
+    let src = PathBuf::from(SEED_SRC);
+    let lib = src.join("lib.rs");
+    let mut items = Vec::new();
+
+    let lib_source = fs::read_to_string(&lib).unwrap_or_default();
+    let root = reexports(&lib_source);
+    file_items(&lib, Docs::Module(&[]), &mut items);
+    collect_items(&src, &lib_source, Docs::Module(&[]), &root, &mut items);
+
+    // Prefer the shortest path when a name's defined more than once.
+    items.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.len().cmp(&b.1.len())));
+    items.dedup_by(|a, b| a.0 == b.0);
+    items
+}
+
+/// The first paragraph of a readme that isn't a heading or badge, on one line.
+fn readme_description(readme: &str) -> Option<String> {
+    let paragraph: Vec<&str> = readme
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty() || line.starts_with('#') || line.starts_with("[!["))
+        .take_while(|line| !line.is_empty())
+        .collect();
+
+    if paragraph.is_empty() {
+        None
+    } else {
+        Some(paragraph.join(" "))
+    }
+}
+
+/// The text of every Rust file under `dir`.
+fn rust_sources(dir: &Path, sources: &mut String) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            rust_sources(&path, sources);
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            sources.push_str(&fs::read_to_string(&path).unwrap_or_default());
+        }
+    }
+}
+
+/// Each example crate, as Rust source for an `Example`, sorted by directory.
+fn examples() -> Vec<String> {
+    let entries = match fs::read_dir(SEED_EXAMPLES) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.join("Cargo.toml").exists())
+        .collect();
+    dirs.sort();
+
+    dirs.iter()
+        .map(|path| {
+            let dir = path.file_name().unwrap_or_default().to_string_lossy();
+            let manifest = fs::read_to_string(path.join("Cargo.toml")).unwrap_or_default();
+            let description = package_value(&manifest, "description")
+                .or_else(|| readme_description(&fs::read_to_string(path.join("README.md")).ok()?))
+                .unwrap_or_default();
+
+            let mut source = String::new();
+            rust_sources(&path.join("src"), &mut source);
+            let features: Vec<&str> = EXAMPLE_FEATURES
+                .iter()
+                .filter(|(_, patterns)| patterns.iter().any(|p| source.contains(p)))
+                .map(|(feature, _)| *feature)
+                .collect();
+
+            format!(
+                concat!(
+                    "    Example {{\n",
+                    "        dir: {:?},\n",
+                    "        name: {:?},\n",
+                    "        description: {:?},\n",
+                    "        features: &{:?},\n",
+                    "        demo: {},\n",
+                    "    }},\n",
+                ),
+                dir,
+                package_value(&manifest, "name").unwrap_or_else(|| dir.to_string()),
+                description,
+                features,
+                Path::new(EXAMPLE_DEMOS).join(&*dir).join("index.html").exists(),
+            )
+        })
+        .collect()
+}
+
+/// Each showcase project, as Rust source for a `Project`.
+fn showcase() -> Vec<String> {
+    let source = fs::read_to_string(SHOWCASE)
+        .unwrap_or_else(|e| panic!("Problem reading {}: {}", SHOWCASE, e));
+
+    let mut errors = Vec::new();
+    let tables = parse_showcase(&source, &mut errors);
+    let mut names = HashSet::new();
+    for table in &tables {
+        check_project(table, &mut names, &mut errors);
+    }
+    if !errors.is_empty() {
+        errors.sort_by_key(|e| e.split(':').next().and_then(|n| n.parse::<usize>().ok()));
+        let errors: Vec<String> = errors.iter().map(|e| format!("{}:{}", SHOWCASE, e)).collect();
+        panic!("Problems in {}:\n{}", SHOWCASE, errors.join("\n"));
+    }
+
+    tables
+        .iter()
+        .map(|table| {
+            let screenshot = table
+                .str("screenshot")
+                .map(|file| format!("/{}/{}", SHOWCASE_SCREENSHOTS, file));
+
+            format!(
+                concat!(
+                    "    Project {{\n",
+                    "        name: {:?},\n",
+                    "        url: {:?},\n",
+                    "        description: {:?},\n",
+                    "        repository: {:?},\n",
+                    "        tags: &{:?},\n",
+                    "        screenshot: {:?},\n",
+                    "    }},\n",
+                ),
+                table.str("name").unwrap_or_default(),
+                table.str("url").unwrap_or_default(),
+                table.str("description").unwrap_or_default(),
+                table.str("repository"),
+                table.strs("tags"),
+                screenshot,
+            )
+        })
+        .collect()
+}
+
+/// The code in a line of an example hidden on the site, mdBook-style: `# ` or a
+/// lone `#`, eg `# struct Model;`. Attributes like `#[derive(Clone)]` aren't hidden.
+fn hidden_line(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if trimmed == "#" {
+        Some("")
+    } else {
+        trimmed.strip_prefix("# ")
+    }
+}
+
+/// A module for the test crate, with the example as the body of `main`, unless
+/// it has one; as rustdoc does. `use seed::prelude::*` is implied, and since
+/// `#[macro_use] extern crate seed` can only go at the crate root, the test has
+/// it there instead. Clippy's lints don't apply to examples, as in doctests.
+fn example_module(page: &str, line: usize, code: &[&str]) -> String {
+    let code: Vec<&str> = code
+        .iter()
+        .map(|l| hidden_line(l).unwrap_or(l))
+        .filter(|l| !["#[macro_use]", "extern crate seed;"].contains(&l.trim()))
+        .collect();
+    let code = code.join("\n");
+
+    let body = if code.contains("fn main(") {
+        code
+    } else {
+        format!("fn main() {{\n{}\n}}", code)
+    };
+
+    format!(
+        "// {dir}/{page}.md:{line}\n#[allow(dead_code, unused, clippy::all)]\nmod {module}_{line} {{\n\
+         use seed::prelude::*;\n\n{body}\n}}\n\n",
+        dir = GUIDE_MARKDOWN,
+        page = page,
+        module = page.replace(&['-', '/'][..], "_"),
+        line = line,
+        body = body,
+    )
+}
+
+/// Every markdown file under `dir`: the guide's chapters, their translations
+/// and the blog's posts.
+fn markdown_pages(dir: &Path, pages: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("Problem reading {:?}: {}", dir, e));
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            markdown_pages(&path, pages);
+        } else if path.extension() == Some(OsStr::new("md")) {
+            pages.push(path);
+        }
+    }
+}
+
+/// Each page's ```rust blocks, except those marked ```rust,ignore; for code
+/// that can't compile on its own, like server code needing other crates.
+fn guide_examples() -> String {
+    let mut pages = Vec::new();
+    markdown_pages(Path::new(GUIDE_MARKDOWN), &mut pages);
+    pages.sort();
+
+    let mut modules = String::new();
+    for path in &pages {
+        // Eg `blog/orders-is-a-trait`, for the comment and module name.
+        let page = path
+            .strip_prefix(GUIDE_MARKDOWN)
+            .unwrap_or(path)
+            .with_extension("")
+            .to_string_lossy()
+            .replace('\\', "/");
+        let source = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Problem reading {:?}: {}", path, e));
+
+        // Other languages' blocks are tracked too, so their closing fences aren't
+        // taken for openings.
+        let mut in_block = false;
+        let mut example: Option<(usize, Vec<&str>)> = None;
+        for (i, line) in source.lines().enumerate() {
+            let fence = line.trim_start();
+            if !fence.starts_with("```") {
+                if let Some((_, code)) = &mut example {
+                    code.push(line);
+                }
+            } else if in_block {
+                in_block = false;
+                if let Some((start, code)) = example.take() {
+                    modules.push_str(&example_module(&page, start, &code));
+                }
+            } else {
+                in_block = true;
+                let info: Vec<&str> = fence.trim_matches('`').split(',').map(str::trim).collect();
+                if info[0] == "rust" && !info.contains(&"ignore") {
+                    example = Some((i + 1, Vec::new()));
+                }
+            }
+        }
+    }
+    modules
+}
+
+fn main() {
+    for path in &[
+        SEED_MANIFEST,
+        SEED_SRC,
+        SEED_CHANGELOG,
+        VENDORED_CHANGELOG,
+        SEED_EXAMPLES,
+        EXAMPLE_DEMOS,
+        SHOWCASE,
+        SHOWCASE_SCREENSHOTS,
+        GUIDE_MARKDOWN,
+    ] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    let changelog = fs::read_to_string(SEED_CHANGELOG)
+        .or_else(|_| fs::read_to_string(VENDORED_CHANGELOG))
+        .unwrap_or_else(|e| panic!("Problem reading {}: {}", VENDORED_CHANGELOG, e));
+
+    let version = fs::read_to_string(SEED_MANIFEST)
+        .ok()
+        .and_then(|manifest| package_value(&manifest, "version"))
+        .or_else(|| changelog_version(&changelog))
+        .unwrap_or_else(|| panic!("No Seed version in {} or its changelog", SEED_MANIFEST));
+
+    let out_dir = env::var("OUT_DIR").expect("Cargo sets OUT_DIR for build scripts");
+    let out_dir = Path::new(&out_dir);
+
+    let mut changelog_html = String::new();
+    pulldown_cmark::html::push_html(&mut changelog_html, pulldown_cmark::Parser::new(&changelog));
+    fs::write(out_dir.join("CHANGELOG.html"), changelog_html)
+        .expect("Problem writing the changelog");
+
+    let items: String = api_items()
+        .iter()
+        .map(|(name, path)| format!("    ({:?}, {:?}),\n", name, path))
+        .collect();
+    fs::write(
+        out_dir.join("api_items.rs"),
+        format!("const API_ITEMS: &[(&str, &str)] = &[\n{}];\n", items),
+    )
+    .expect("Problem writing the API items");
+
+    fs::write(
+        out_dir.join("examples.rs"),
+        format!("pub const EXAMPLES: &[Example] = &[\n{}];\n", examples().concat()),
+    )
+    .expect("Problem writing the examples");
+
+    fs::write(
+        out_dir.join("showcase.rs"),
+        format!("pub const PROJECTS: &[Project] = &[\n{}];\n", showcase().concat()),
+    )
+    .expect("Problem writing the showcase");
+
+    fs::write(out_dir.join("guide_examples.rs"), guide_examples())
+        .expect("Problem writing the guide's examples");
+
+    println!("cargo:rustc-env=SEED_VERSION={}", version);
+}
@@ -0,0 +1,195 @@
+//! Reading and checking `showcase.toml`, for `build.rs`. Mistakes fail the
+//! build, with the line they're on, instead of showing up on the site.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use toml::Value;
+
+/// Showcase screenshots, which entries refer to by filename.
+pub const SHOWCASE_SCREENSHOTS: &str = "public/showcase";
+/// Keys a showcase project can have. The first three are required.
+const PROJECT_KEYS: &[&str] = &[
+    "name",
+    "url",
+    "description",
+    "repository",
+    "tags",
+    "screenshot",
+];
+
+/// A `[[project]]` table in the showcase, and the lines it and its keys are on.
+pub struct Table {
+    pub line: usize,
+    pub values: toml::value::Table,
+    key_lines: HashMap<String, usize>,
+}
+
+impl Table {
+    pub fn str(&self, key: &str) -> Option<&str> {
+        self.values.get(key).and_then(Value::as_str)
+    }
+
+    /// The strings in a list, like `tags`.
+    pub fn strs(&self, key: &str) -> Vec<&str> {
+        match self.values.get(key) {
+            Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn line(&self, key: &str) -> usize {
+        self.key_lines.get(key).copied().unwrap_or(self.line)
+    }
+}
+
+/// Each table header, like `[[project]]`, the line it's on, and the lines its
+/// keys are set on; keys before the first header are under "". `toml` doesn't
+/// keep positions, so errors use these.
+fn sections(source: &str) -> Vec<(String, usize, HashMap<String, usize>)> {
+    let mut sections = vec![(String::new(), 1, HashMap::new())];
+
+    for (i, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            let header = line.split('#').next().unwrap_or_default().trim();
+            sections.push((header.to_string(), i + 1, HashMap::new()));
+        } else if line.starts_with('#') {
+            continue;
+        } else if let Some(eq) = line.find('=') {
+            let key = line[..eq].trim().trim_matches('"').to_string();
+            if let Some((_, _, keys)) = sections.last_mut() {
+                keys.entry(key).or_insert(i + 1);
+            }
+        }
+    }
+    sections
+}
+
+/// Parse the showcase's `[[project]]` tables. Errors are `line: message`.
+pub fn parse_showcase(source: &str, errors: &mut Vec<String>) -> Vec<Table> {
+    let mut document: toml::value::Table = match toml::from_str(source) {
+        Ok(document) => document,
+        Err(e) => {
+            let line = e.line_col().map_or(1, |(line, _)| line + 1);
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            errors.push(format!("{}: {}", line, message));
+            return Vec::new();
+        }
+    };
+    let mut sections = sections(source);
+
+    let projects = document.remove("project");
+    for key in document.keys() {
+        let line = sections
+            .iter()
+            .find(|(header, _, _)| header.trim_matches(|c| c == '[' || c == ']') == key)
+            .map(|(_, line, _)| *line)
+            .or_else(|| sections[0].2.get(key).copied())
+            .unwrap_or(1);
+        errors.push(format!(
+            "{}: `{}` is outside a `[[project]]` table",
+            line, key
+        ));
+    }
+
+    let projects = match projects {
+        Some(Value::Array(projects)) => projects,
+        Some(_) => {
+            let line = sections.iter().find(|(header, _, _)| header == "[project]");
+            let line = line.map_or(1, |(_, line, _)| *line);
+            errors.push(format!("{}: projects should be `[[project]]` tables", line));
+            return Vec::new();
+        }
+        None => return Vec::new(),
+    };
+
+    sections.retain(|(header, _, _)| header == "[[project]]");
+    projects
+        .into_iter()
+        .zip(sections)
+        .filter_map(|(project, (_, line, key_lines))| match project {
+            Value::Table(values) => Some(Table {
+                line,
+                values,
+                key_lines,
+            }),
+            _ => {
+                errors.push(format!("{}: projects should be `[[project]]` tables", line));
+                None
+            }
+        })
+        .collect()
+}
+
+/// Check a showcase project's keys and values, and that its name is new.
+pub fn check_project(table: &Table, names: &mut HashSet<String>, errors: &mut Vec<String>) {
+    for (key, value) in &table.values {
+        let n = table.line(key);
+        if !PROJECT_KEYS.contains(&key.as_str()) {
+            errors.push(format!(
+                "{}: unknown key `{}`; expected one of {}",
+                n,
+                key,
+                PROJECT_KEYS.join(", ")
+            ));
+            continue;
+        }
+
+        match (key.as_str(), value) {
+            ("tags", Value::Array(tags)) => {
+                let valid = |tag: &str| {
+                    let slug = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-';
+                    !tag.is_empty() && tag.chars().all(slug)
+                };
+                for tag in tags {
+                    match tag.as_str() {
+                        Some(tag) if valid(tag) => (),
+                        Some(tag) => errors.push(format!(
+                            "{}: tag \"{}\" should be lowercase letters, digits and hyphens",
+                            n, tag
+                        )),
+                        None => errors.push(format!("{}: tag {} should be a string", n, tag)),
+                    }
+                }
+            }
+            ("tags", _) => errors.push(format!("{}: `tags` should be a list, like [\"games\"]", n)),
+            ("url", Value::String(url)) | ("repository", Value::String(url))
+                if !url.starts_with("https://") && !url.starts_with("http://") =>
+            {
+                errors.push(format!(
+                    "{}: `{}` should be a full URL, like https://…",
+                    n, key
+                ))
+            }
+            ("screenshot", Value::String(file))
+                if !Path::new(SHOWCASE_SCREENSHOTS).join(file).is_file() =>
+            {
+                errors.push(format!(
+                    "{}: no screenshot at {}/{}",
+                    n, SHOWCASE_SCREENSHOTS, file
+                ))
+            }
+            (_, Value::String(s)) if s.trim().is_empty() => {
+                errors.push(format!("{}: `{}` is empty", n, key))
+            }
+            (_, Value::String(_)) => (),
+            _ => errors.push(format!("{}: `{}` should be a string", n, key)),
+        }
+    }
+
+    for key in &PROJECT_KEYS[..3] {
+        if !table.values.contains_key(*key) {
+            errors.push(format!("{}: project is missing `{}`", table.line, key));
+        }
+    }
+    if let Some(name) = table.str("name") {
+        if !names.insert(name.to_lowercase()) {
+            errors.push(format!(
+                "{}: there's already a project named \"{}\"",
+                table.line, name
+            ));
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Writes the theme's tokens to `theme.css`, so the page is styled before the
+//! wasm loads. Run with `cargo make theme`, which `cargo make build` does first;
+//! `tests/theme.rs` checks the file is current.
+
+use std::fs;
+
+#[path = "../src/theme.rs"]
+#[allow(dead_code)]  // Views use more of it than we do.
+mod theme;
+
+const THEME_SOURCE: &str = "src/theme.rs";
+/// Served beside `style.css`, which uses its custom properties.
+const THEME_CSS: &str = "theme.css";
+
+fn main() {
+    let css = format!(
+        "/* Generated by `cargo make theme`, from `{}`. */\n{}\n",
+        THEME_SOURCE,
+        theme::THEME.stylesheet()
+    );
+    fs::write(THEME_CSS, css).expect("Problem writing the theme");
+}
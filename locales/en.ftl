@@ -0,0 +1,102 @@
+# UI text, in Fluent syntax: https://projectfluent.org/fluent/guide/
+# Every catalog must define the same ids; `cargo test` checks this.
+
+## Header
+skip-to-content = Skip to content
+nav-main-label = Main
+version-crate = This version on crates.io
+version-docs = API docs for this version
+nav-docs = docs
+nav-guide = Guide
+nav-examples = Examples
+nav-blog = Blog
+nav-showcase = Showcase
+nav-changelog = Changelog
+nav-repo = Repo
+nav-quickstart-repo = Quickstart repo
+nav-crate = Crate
+nav-api-docs = API docs
+nav-resources = Resources
+nav-external = (opens in a new tab)
+breadcrumbs-label = Breadcrumbs
+nav-languages-label = Language
+code-theme-label = Code colours
+offline-ready = Available offline
+offline-ready-detail = This site has been saved, and will keep working without a network connection.
+
+## Title
+title-tagline = A Rust framework for creating web apps
+title-view-syntax = Expressive view syntax
+title-error-checking = Compile-time error checking
+title-architecture = Clean architecture
+
+## Guide
+nav-guide-label = Guide sections
+guide-untranslated = This section hasn't been translated yet, so it's shown in English.
+guide-all-link = Entire guide
+guide-all-title = The Seed guide
+guide-toc-label = Contents
+guide-requires = Needs Seed { $version } or later
+guide-tags-label = Topics
+guide-pagination-label = Previous and next sections
+guide-previous = ← { $title }
+guide-next = { $title } →
+heading-link = Link to this section
+heading-copy-link = Copy link
+
+## Examples
+examples-title = Examples
+examples-intro = Complete apps from Seed's repository, each showing how to use some of its features.
+examples-search = Search examples
+examples-filter-label = Filter by feature
+examples-all = All
+examples-source = Source
+examples-demo = Live demo
+examples-none = The examples are listed when this site is built alongside Seed's repository.
+examples-no-match = No examples match.
+examples-count = { $count ->
+    [one] { $count } example
+   *[other] { $count } examples
+}
+
+## Blog
+blog-title = Blog
+blog-tagged = Posts tagged “{ $tag }”
+blog-all-posts = All posts
+blog-by = By { $author }
+blog-tags-label = Tags
+blog-newer = Newer posts
+blog-older = Older posts
+blog-page = Page { $page } of { $pages }
+blog-pagination-label = Blog pages
+blog-feed = Subscribe with the Atom feed
+blog-none = No posts yet.
+blog-not-found = There's no post at this address.
+
+## Showcase
+showcase-title = Showcase
+showcase-intro = Apps and sites people have built with Seed.
+showcase-filter-label = Filter by tag
+showcase-all = All
+showcase-source = Source
+showcase-screenshot = Screenshot of { $name }
+showcase-add = Built something with Seed? Add it to showcase.toml in this site's repository.
+
+## Command palette and keyboard shortcuts
+
+palette-label = Go to
+palette-placeholder = Search sections, headings and pages
+palette-no-results = Nothing matches.
+palette-kind-page = Page
+palette-kind-section = Guide
+palette-kind-heading = Heading
+palette-kind-external = External link
+shortcuts-title = Keyboard shortcuts
+shortcut-palette = Search and go to a page
+shortcut-next = Next guide section
+shortcut-previous = Previous guide section
+shortcut-help = Show these shortcuts
+shortcut-close = Close
+
+## Footer
+footer-copyright = © { $year } David O'Connor
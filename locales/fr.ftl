@@ -0,0 +1,102 @@
+# UI text, in Fluent syntax: https://projectfluent.org/fluent/guide/
+# Every catalog must define the same ids; `cargo test` checks this.
+
+## Header
+skip-to-content = Aller au contenu
+nav-main-label = Principale
+version-crate = Cette version sur crates.io
+version-docs = Documentation de l'API pour cette version
+nav-docs = doc
+nav-guide = Guide
+nav-examples = Exemples
+nav-blog = Blog
+nav-showcase = Vitrine
+nav-changelog = Changements
+nav-repo = Dépôt
+nav-quickstart-repo = Dépôt de démarrage
+nav-crate = Crate
+nav-api-docs = Documentation de l'API
+nav-resources = Ressources
+nav-external = (s'ouvre dans un nouvel onglet)
+breadcrumbs-label = Fil d'Ariane
+nav-languages-label = Langue
+code-theme-label = Couleurs du code
+offline-ready = Disponible hors ligne
+offline-ready-detail = Ce site a été enregistré et fonctionnera sans connexion réseau.
+
+## Title
+title-tagline = Un framework Rust pour créer des applications web
+title-view-syntax = Une syntaxe de vue expressive
+title-error-checking = Des erreurs détectées à la compilation
+title-architecture = Une architecture claire
+
+## Guide
+nav-guide-label = Sections du guide
+guide-untranslated = Cette section n'a pas encore été traduite ; elle est affichée en anglais.
+guide-all-link = Guide complet
+guide-all-title = Le guide de Seed
+guide-toc-label = Sommaire
+guide-requires = Nécessite Seed { $version } ou plus récent
+guide-tags-label = Sujets
+guide-pagination-label = Sections précédente et suivante
+guide-previous = ← { $title }
+guide-next = { $title } →
+heading-link = Lien vers cette section
+heading-copy-link = Copier le lien
+
+## Examples
+examples-title = Exemples
+examples-intro = Des applications complètes du dépôt de Seed, qui montrent chacune comment utiliser certaines de ses fonctionnalités.
+examples-search = Rechercher des exemples
+examples-filter-label = Filtrer par fonctionnalité
+examples-all = Tous
+examples-source = Source
+examples-demo = Démo en ligne
+examples-none = Les exemples sont listés quand ce site est construit à côté du dépôt de Seed.
+examples-no-match = Aucun exemple ne correspond.
+examples-count = { $count ->
+    [one] { $count } exemple
+   *[other] { $count } exemples
+}
+
+## Blog
+blog-title = Blog
+blog-tagged = Articles avec l'étiquette « { $tag } »
+blog-all-posts = Tous les articles
+blog-by = Par { $author }
+blog-tags-label = Étiquettes
+blog-newer = Articles plus récents
+blog-older = Articles plus anciens
+blog-page = Page { $page } sur { $pages }
+blog-pagination-label = Pages du blog
+blog-feed = S'abonner au flux Atom
+blog-none = Aucun article pour l'instant.
+blog-not-found = Il n'y a pas d'article à cette adresse.
+
+## Showcase
+showcase-title = Vitrine
+showcase-intro = Des applications et des sites construits avec Seed.
+showcase-filter-label = Filtrer par étiquette
+showcase-all = Tous
+showcase-source = Source
+showcase-screenshot = Capture d'écran de { $name }
+showcase-add = Vous avez construit quelque chose avec Seed ? Ajoutez-le à showcase.toml dans le dépôt de ce site.
+
+## Command palette and keyboard shortcuts
+
+palette-label = Aller à
+palette-placeholder = Chercher des sections, titres et pages
+palette-no-results = Aucun résultat.
+palette-kind-page = Page
+palette-kind-section = Guide
+palette-kind-heading = Titre
+palette-kind-external = Lien externe
+shortcuts-title = Raccourcis clavier
+shortcut-palette = Chercher et aller à une page
+shortcut-next = Section suivante du guide
+shortcut-previous = Section précédente du guide
+shortcut-help = Afficher ces raccourcis
+shortcut-close = Fermer
+
+## Footer
+footer-copyright = © { $year } David O'Connor
@@ -0,0 +1,64 @@
+# List the files the site needs, for the service worker (`sw.js`) to precache
+# so the homepage works offline. Run after building the release wasm; the
+# version changes whenever any of the files do, and is stamped into `sw.js`, so
+# browsers install the new worker, which refreshes the cache.
+
+import glob
+import hashlib
+import json
+import os
+import re
+
+# Guide content is embedded in the wasm file, so there's nothing separate to
+# fetch for it. Add patterns here for any assets loaded later at runtime.
+ASSETS = [
+    "index.html",
+    "theme.css",
+    "style.css",
+    "syntax_style.css",
+    "code_themes.css",
+    "pkg/package.js",
+    "pkg/package_bg.wasm",
+    "public/**/*",
+]
+
+# Cross-origin files we can't hash; the service worker caches what these
+# load (eg font files) as they're fetched.
+EXTERNAL = [
+    "https://fonts.googleapis.com/css?family=Karla",
+]
+
+
+def main():
+    # Including what's in subdirectories, like `public/showcase/`.
+    files = sorted(
+        f for pattern in ASSETS for f in glob.glob(pattern, recursive=True)
+        if os.path.isfile(f)
+    )
+
+    digest = hashlib.sha256()
+    for filename in files:
+        with open(filename, 'rb') as f:
+            digest.update(filename.encode("utf8"))
+            digest.update(f.read())
+
+    manifest = {
+        "version": digest.hexdigest()[:16],
+        # The root is served from `index.html`, as are all routes; see `_redirects`.
+        "files": ["/"] + ["/" + f.replace("\\", "/") for f in files] + EXTERNAL,
+    }
+
+    with open("asset-manifest.json", 'w', encoding="utf8") as f:
+        json.dump(manifest, f, indent=2)
+        f.write("\n")
+
+    with open("sw.js", encoding="utf8") as f:
+        worker = f.read()
+    stamp = f"const VERSION = '{manifest['version']}';"
+    worker = re.sub(r"^const VERSION = '\w*';$", stamp, worker, count=1, flags=re.MULTILINE)
+    with open("sw.js", 'w', encoding="utf8") as f:
+        f.write(worker)
+
+
+if __name__ == "__main__":
+    main()
@@ -1,5 +1,7 @@
+Copy-Item ../seed/CHANGELOG.md vendor/seed/CHANGELOG.md
 python setup_markdown.py
 cargo make build_release
+python make_manifest.py
 rm pkg/.gitignore
 git add .
 git commit -am "update"
@@ -1,5 +1,7 @@
+cp ../seed/CHANGELOG.md vendor/seed/CHANGELOG.md
 python3 setup_markdown.py
 cargo make build_release
+python3 make_manifest.py
 rm pkg/.gitignore
 git add .
 git commit -am "update"
@@ -6,58 +6,608 @@
 
 # Rererence https://pandoc.org/MANUAL.html
 
+import html
+import json
 import os
 import re
+import subprocess
+import sys
+import zlib
 
+# Pandoc marks highlighted tokens with classes, like `kw` for keywords; these
+# themes give them colours in `code_themes.css`, and readers pick one at runtime.
 # ./pandoc --list-highlight-styles
 # pygments tango espresso zenburn kate monochrome breezedark haddock
-STYLE = "tango"
-VERSION = "0.4.1"
+DEFAULT_CODE_THEME = "tango"
+CODE_THEMES = {
+    "tango": "Tango",
+    "pygments": "Pygments",
+    "zenburn": "Zenburn",
+    "breezedark": "Breeze Dark",
+}
+
+# Pandoc's token types, and the classes it gives them.
+TOKEN_CLASSES = {
+    "Alert": "al",
+    "Annotation": "an",
+    "Attribute": "at",
+    "BaseN": "bn",
+    "BuiltIn": "bu",
+    "ControlFlow": "cf",
+    "Char": "ch",
+    "Constant": "cn",
+    "Comment": "co",
+    "CommentVar": "cv",
+    "Documentation": "do",
+    "DataType": "dt",
+    "DecVal": "dv",
+    "Error": "er",
+    "Extension": "ex",
+    "Float": "fl",
+    "Function": "fu",
+    "Import": "im",
+    "Information": "in",
+    "Keyword": "kw",
+    "Operator": "op",
+    "Other": "ot",
+    "Preprocessor": "pp",
+    "RegionMarker": "re",
+    "SpecialChar": "sc",
+    "SpecialString": "ss",
+    "String": "st",
+    "Variable": "va",
+    "VerbatimString": "vs",
+    "Warning": "wa",
+}
+
+SITE_URL = "https://seed-rs.org"
+SEED_MANIFEST = "../seed/Cargo.toml"
+VENDORED_CHANGELOG = "vendor/seed/CHANGELOG.md"
+
+# English lives directly in `markdown/`; translations in `markdown/<code>/`,
+# using the same filenames. Sections without a translation fall back to English.
+DEFAULT_LANGUAGE = "en"
+LANGUAGES = {
+    "en": "English",
+    "fr": "Français",
+}
+
+# Callouts, written as pandoc fenced divs:
+#
+#     ::: warning
+#     Text that readers shouldn't miss.
+#     :::
+#
+# By kind: an icon, and a title in each language.
+ADMONITIONS = {
+    "note": ("ℹ", {"en": "Note", "fr": "Remarque"}),
+    "tip": ("💡", {"en": "Tip", "fr": "Astuce"}),
+    "warning": ("⚠", {"en": "Warning", "fr": "Attention"}),
+    "breaking": ("💥", {"en": "Breaking change", "fr": "Changement incompatible"}),
+}
+
+
+# Guide chapters are the markdown files in `markdown/`, listed by their front
+# matter. `title` and `order` are required; by default, `slug` comes from the
+# filename. Drafts are left out unless this is run with `--drafts`.
+GUIDE_FIELDS = ["title", "order"]
+INCLUDE_DRAFTS = "--drafts" in sys.argv
+
+# Posts, as `<slug>.md`, with front matter for their title, date and so on.
+BLOG_DIR = "markdown/blog"
+BLOG_FIELDS = ["title", "date", "author"]  # Required; `tags` and `summary` are optional.
+
+
+def seed_version():
+    """The version of Seed we build against; `seed` is a path dependency. Without
+    it, use the newest release in the vendored changelog. `build.rs` does the same
+    for the version shown in the header."""
+    if not os.path.exists(SEED_MANIFEST):
+        with open(VENDORED_CHANGELOG, encoding="utf8") as f:
+            return re.search(r'^## v?(\d[^\s]*)', f.read(), re.MULTILINE).group(1)
+
+    with open(SEED_MANIFEST, encoding="utf8") as f:
+        manifest = f.read()
+
+    package = manifest[manifest.index("[package]"):]
+    return re.search(r'^version\s*=\s*"([^"]+)"', package, re.MULTILINE).group(1)
+
+
+def source_dir(lang):
+    return "markdown" if lang == DEFAULT_LANGUAGE else f"markdown/{lang}"
+
+
+def output_dir(lang):
+    return "src/book" if lang == DEFAULT_LANGUAGE else f"src/book/{lang}"
+
+
+# A tab name in a code block's fence, eg ```bash,tab=Windows
+TAB_ATTR = re.compile(r',\s*tab=([^,`]+)')
+
+
+def tab_groups(markdown):
+    """Wrap runs of code blocks with tab names in a `code-tabs` div, and each
+    block in a `code-tab` one, for `tab_controls` to finish after pandoc. Blocks
+    in a group may only be separated by blank lines."""
+    lines, in_block, in_tab, in_group = [], False, False, False
+
+    def close_group():
+        lines.extend(['', '</div>', ''])
+
+    for line in markdown.split('\n'):
+        fence = line.lstrip()
+        if fence.startswith('```') and in_block:
+            lines.append(line)
+            if in_tab:
+                lines.extend(['', '</div>'])
+            in_block, in_tab = False, False
+            continue
+
+        if fence.startswith('```'):
+            in_block = True
+            tab = TAB_ATTR.search(fence)
+            if tab:
+                if not in_group:
+                    lines.extend(['', '<div class="code-tabs">', ''])
+                name = html.escape(tab.group(1).strip())
+                lines.extend([f'<div class="code-tab" data-tab="{name}">', ''])
+                line = line[:len(line) - len(fence)] + TAB_ATTR.sub('', fence, count=1)
+                in_tab, in_group = True, True
+        if in_group and not in_tab and line.strip():
+            close_group()
+            in_group = False
+        lines.append(line)
+
+    if in_group:
+        close_group()
+    return '\n'.join(lines)
+
+
+def tab_controls(body):
+    """Add a tab list to each group from `tab_groups`, and hide all but its
+    first tab. `src/code_tabs.rs` switches them, and shows the reader's choice."""
+    groups = body.split('<div class="code-tabs">')
+    for i, group in enumerate(groups[1:], 1):
+        names = re.findall(r'<div class="code-tab" data-tab="([^"]*)">', group)
+        buttons = "".join(
+            f'<button role="tab" data-tab="{name}" aria-selected="{"true" if j == 0 else "false"}">'
+            f'{name}</button>'
+            for j, name in enumerate(names)
+        )
+        for j, name in enumerate(names):
+            group = group.replace(
+                f'<div class="code-tab" data-tab="{name}">',
+                f'<div class="code-tab" data-tab="{name}" role="tabpanel"{"" if j == 0 else " hidden"}>',
+                1,
+            )
+        groups[i] = f'<div class="code-tab-list" role="tablist">{buttons}</div>' + group
+    return '<div class="code-tabs">'.join(groups)
+
+
+def admonitions(body, lang):
+    """Give the divs pandoc makes for callouts their icon and title; `style.css`
+    styles each kind."""
+    def callout(match):
+        kind = match.group(1)
+        icon, titles = ADMONITIONS[kind]
+        title = titles.get(lang, titles[DEFAULT_LANGUAGE])
+        return (
+            f'<div class="admonition admonition-{kind}" role="note">'
+            f'<p class="admonition-title"><span class="admonition-icon" aria-hidden="true">'
+            f'{icon}</span>{title}</p>'
+        )
+
+    kinds = "|".join(ADMONITIONS)
+    return re.sub(rf'<div class="({kinds})">', callout, body)
+
+
+def hide_example_setup(markdown):
+    """Drop the lines of Rust examples that are only there so they compile, as
+    mdBook does: `# ` or a lone `#`. Also drop attributes like `,ignore` from
+    their fences, which pandoc would take as part of the language. See
+    `tests/guide_examples.rs`."""
+    lines, in_block, in_rust = [], False, False
+    for line in markdown.split('\n'):
+        fence = line.lstrip()
+        if fence.startswith('```'):
+            info = fence.strip('`').split(',')
+            if not in_block and info[0].strip() == 'rust':
+                line = line[:len(line) - len(fence)] + '```rust'
+                in_rust = True
+            elif in_block:
+                in_rust = False
+            in_block = not in_block
+        elif in_rust and (fence == '#' or fence.startswith('# ')):
+            continue
+        lines.append(line)
+    return '\n'.join(lines)
+
+
+def convert(filename, lang=DEFAULT_LANGUAGE):
+    """Run pandoc on a markdown file, without its front matter, and return the
+    HTML body it produces."""
+    _, markdown = front_matter(f'./{source_dir(lang)}/{filename}.md')
+    markdown = hide_example_setup(tab_groups(markdown))
+    data = subprocess.run(
+        ["pandoc", "-f", "markdown", "-s", "--metadata", f"pagetitle={filename}"],
+        input=markdown.encode("utf8"), capture_output=True, check=True,
+    ).stdout.decode("utf8")
+
+    # Trim everything except for the HTML body; Pandoc outputs full files.
+    regex = re.compile(r'<body>(.*?)</body>', re.DOTALL)
+    m = re.search(regex, data)
+
+    return m.groups(0)[0]
+
+
+def front_matter_value(value):
+    """A string, number, boolean, or list of them, in YAML or TOML."""
+    value = value.strip()
+    if value.startswith('[') and value.endswith(']'):
+        return [front_matter_value(v) for v in value[1:-1].split(',') if v.strip()]
+    if len(value) > 1 and value[0] == value[-1] and value[0] in "'\"":
+        return value[1:-1]
+    if value in ("true", "false"):
+        return value == "true"
+    if re.fullmatch(r'-?\d+', value):
+        return int(value)
+    return value
+
+
+def front_matter(path):
+    """Metadata at the top of a markdown file, and the markdown after it. This can
+    be YAML between `---` lines, like `title: Views`, or TOML between `+++` lines,
+    like `title = "Views"`; we support single-line values."""
+    with open(path, encoding="utf8") as f:
+        text = f.read()
+
+    m = re.match(r'(---|\+\+\+)\n(.*?)\n(?:---|\.\.\.|\+\+\+)\n', text, re.DOTALL)
+    if not m:
+        return {}, text
+
+    separator = ':' if m.group(1) == '---' else '='
+    meta = {}
+    for line in m.group(2).splitlines():
+        if not line.strip() or line.lstrip().startswith('#'):
+            continue
+        key, sep, value = line.partition(separator)
+        if not sep:
+            raise SystemExit(
+                f'{path}: expected `key{separator} value` in front matter, not "{line}"'
+            )
+        meta[key.strip()] = front_matter_value(value)
+    return meta, text[m.end():]
+
+
+def minify(body):
+    """Pandoc wraps every line of highlighted code in an anchor; we don't use
+    them, and they make up much of the output's size."""
+    return re.sub(
+        r'<a class="sourceLine" id="[^"]*" title="\d+">(.*?)</a>', r'\1', body
+    )
+
+
+def process(body, version, lang=DEFAULT_LANGUAGE):
+    # Update all instances of the version, so we don't have to in Markdown.
+    body = re.sub(r'seed/0\.\d\.(\d{1,3})', "seed/" + version, body)
+    body = re.sub(r'seed = &quot;\^[\d.]+&quot;', f'seed = &quot;^{version}&quot;', body)
+
+    return minify(admonitions(tab_controls(body), lang))
+
+
+def plain_text(body):
+    return html.unescape(re.sub(r'<[^>]+>', '', body)).strip()
+
+
+def rust_str(value):
+    return json.dumps(value, ensure_ascii=False)
+
+
+def headings(body, indent):
+    """(level, id, text) for each h1-h3, as a Rust slice literal; eg for a table
+    of contents."""
+    items = []
+    for level, id_, inner in re.findall(r'<h([1-3]) id="([^"]*)">(.*?)</h\1>', body, re.DOTALL):
+        items.append(f'{indent}    ({level}, "{id_}", {rust_str(plain_text(inner))}),\n')
+    return "&[\n" + "".join(items) + indent + "]"
+
+
+def compress(path, body):
+    # Content is stored compressed, and decompressed the first time the
+    # section is viewed.
+    os.makedirs(os.path.dirname(path), exist_ok=True)
+    with open(path, 'wb') as f:
+        f.write(zlib.compress(body.encode("utf8"), 9))
+
+
+def write_section(filename, body, title, translations, titles):
+    """`translations` and `titles` are by language; translations without a title
+    in their front matter use the English one."""
+    compress(f'./src/book/{filename}.html.z', body)
+    for lang, translated in translations.items():
+        compress(f'./src/book/{lang}/{filename}.html.z', translated)
+
+    # Create a new rust file
+    langs = "".join(f'"{lang}", ' for lang in translations).rstrip(", ")
+    with open(f'./src/book/{filename}.rs', 'w', encoding="utf8") as f:
+        f.write(f'pub const TRANSLATIONS: &[&str] = &[{langs}];\n\n')
+        if not translations:
+            f.write('pub fn title(_lang: &str) -> &\'static str {\n')
+            f.write(f'    {rust_str(title)}\n')
+            f.write('}\n\n')
+            f.write('pub fn text(_lang: &str) -> String {\n')
+            f.write(f'    super::decompress(include_bytes!("{filename}.html.z"))\n')
+            f.write('}\n\n')
+            f.write('pub fn headings(_lang: &str) -> &\'static [super::Heading] {\n')
+            f.write(f'    {headings(body, "    ")}\n')
+            f.write('}\n')
+            return
+
+        f.write('pub fn title(lang: &str) -> &\'static str {\n')
+        f.write('    match lang {\n')
+        for lang in translations:
+            f.write(f'        "{lang}" => {rust_str(titles.get(lang, title))},\n')
+        f.write(f'        _ => {rust_str(title)},\n')
+        f.write('    }\n')
+        f.write('}\n\n')
+        f.write('pub fn text(lang: &str) -> String {\n')
+        f.write('    super::decompress(match lang {\n')
+        for lang in translations:
+            f.write(f'        "{lang}" => &include_bytes!("{lang}/{filename}.html.z")[..],\n')
+        f.write(f'        _ => &include_bytes!("{filename}.html.z")[..],\n')
+        f.write('    })\n')
+        f.write('}\n\n')
+        f.write('pub fn headings(lang: &str) -> &\'static [super::Heading] {\n')
+        f.write('    match lang {\n')
+        for lang, translated in translations.items():
+            f.write(f'        "{lang}" => {headings(translated, "        ")},\n')
+        f.write(f'        _ => {headings(body, "        ")},\n')
+        f.write('    }\n')
+        f.write('}\n')
+
+
+def version_tuple(version):
+    return tuple(int(part) for part in re.findall(r'\d+', str(version)))
+
+
+def guide_chapters(version):
+    """(filename, front matter) for each chapter to include, in menu order."""
+    chapters = []
+    for name in sorted(os.listdir("markdown")):
+        if not name.endswith(".md"):
+            continue
+        filename, path = name[:-len(".md")], f'markdown/{name}'
+
+        meta, _ = front_matter(path)
+        missing = [field for field in GUIDE_FIELDS if field not in meta]
+        if missing:
+            raise SystemExit(f'{path}: missing {", ".join(missing)} in front matter')
+        if not isinstance(meta["order"], int):
+            raise SystemExit(f'{path}: order should be a number, not "{meta["order"]}"')
+
+        if meta.get("draft") and not INCLUDE_DRAFTS:
+            print(f'Skipping {path}: it\'s a draft')
+            continue
+        # Eg a chapter written ahead of a release, about features we don't build with yet.
+        minimum = meta.get("min_seed_version")
+        if minimum and version_tuple(minimum) > version_tuple(version):
+            print(f'Skipping {path}: it needs Seed {minimum}, and we have {version}')
+            continue
+
+        meta.setdefault("slug", filename.replace("_", "-"))
+        meta["tags"] = [tag.lower().replace(" ", "-") for tag in meta.get("tags", [])]
+        chapters.append((filename, meta))
+
+    chapters.sort(key=lambda chapter: chapter[1]["order"])
+
+    slugs = [meta["slug"] for _, meta in chapters]
+    duplicates = {slug for slug in slugs if slugs.count(slug) > 1}
+    if duplicates:
+        raise SystemExit(f'More than one chapter has the slug {", ".join(sorted(duplicates))}')
+
+    return chapters
+
+
+def write_sections(chapters):
+    """The chapter modules, and a `Section` for each in menu order. This is
+    included in `src/book/mod.rs`."""
+    with open('./src/book/sections.rs', 'w', encoding="utf8") as f:
+        f.write('// Generated by setup_markdown.py, from the guide\'s front matter.\n\n')
+        for filename, _ in sorted(chapters):
+            f.write(f'pub mod {filename};\n')
+
+        f.write('\npub const SECTIONS: &[Section] = &[\n')
+        for filename, meta in chapters:
+            tags = ", ".join(rust_str(tag) for tag in meta["tags"])
+            minimum = meta.get("min_seed_version")
+            minimum = f'Some({rust_str(str(minimum))})' if minimum else 'None'
+            f.write('    Section {\n')
+            f.write(f'        slug: {rust_str(meta["slug"])},\n')
+            f.write(f'        file: "{filename}",\n')
+            f.write(f'        title: {filename}::title,\n')
+            f.write(f'        text: {filename}::text,\n')
+            f.write(f'        headings: {filename}::headings,\n')
+            f.write(f'        translations: {filename}::TRANSLATIONS,\n')
+            f.write(f'        summary: {rust_str(meta.get("summary", ""))},\n')
+            f.write(f'        tags: &[{tags}],\n')
+            f.write(f'        min_seed_version: {minimum},\n')
+            f.write('    },\n')
+        f.write('];\n')
+
+
+def highlight_style(theme):
+    """Pandoc's definition of a highlighting theme, as JSON."""
+    output = subprocess.run(
+        ["pandoc", "--print-highlight-style", theme], capture_output=True, check=True
+    ).stdout
+    return json.loads(output)
+
+
+def token_css(style):
+    rules = []
+    if style.get("text-color"):
+        rules.append(f'color: {style["text-color"]};')
+    if style.get("background-color"):
+        rules.append(f'background-color: {style["background-color"]};')
+    if style.get("bold"):
+        rules.append('font-weight: bold;')
+    if style.get("italic"):
+        rules.append('font-style: italic;')
+    if style.get("underline"):
+        rules.append('text-decoration: underline;')
+    return " ".join(rules)
+
+
+def write_code_themes():
+    """A palette per theme, selected by the `data-code-theme` attribute on the
+    root element, and the list of themes for the settings control."""
+    with open('./code_themes.css', 'w', encoding="utf8") as f:
+        f.write('/* Generated by setup_markdown.py, from pandoc\'s highlighting themes. */\n')
+        for theme in CODE_THEMES:
+            style = highlight_style(theme)
+            selector = f'[data-code-theme="{theme}"]'
+
+            f.write(f'\n/* {CODE_THEMES[theme]} */\n')
+            # Also on the code itself, over `style.css`'s background for code.
+            f.write(
+                f'{selector} div.sourceCode, {selector} pre > code.sourceCode '
+                f'{{ {token_css(style)} }}\n'
+            )
+            for token, class_ in TOKEN_CLASSES.items():
+                css = token_css(style["text-styles"].get(token, {}))
+                if css:
+                    f.write(f'{selector} code span.{class_} {{ {css} }} /* {token} */\n')
+
+    with open('./src/book/code_themes.rs', 'w', encoding="utf8") as f:
+        f.write('pub const DEFAULT_CODE_THEME: &str = "' + DEFAULT_CODE_THEME + '";\n\n')
+        f.write('/// (name, label)\n')
+        f.write('pub const CODE_THEMES: &[(&str, &str)] = &[\n')
+        for theme, label in CODE_THEMES.items():
+            f.write(f'    ("{theme}", "{label}"),\n')
+        f.write('];\n')
+
+
+def write_blog(version):
+    """Compress each post, and list them newest first in `src/book/blog.rs`."""
+    posts = []
+    for name in sorted(os.listdir(BLOG_DIR)):
+        if not name.endswith(".md"):
+            continue
+        slug, path = name[:-len(".md")], f'{BLOG_DIR}/{name}'
+
+        meta, _ = front_matter(path)
+        missing = [field for field in BLOG_FIELDS if not meta.get(field)]
+        if missing:
+            raise SystemExit(f'{path}: missing {", ".join(missing)} in front matter')
+        if not re.fullmatch(r'\d{4}-\d{2}-\d{2}', meta["date"]):
+            raise SystemExit(f'{path}: date should be like 2019-07-22, not "{meta["date"]}"')
+
+        body = process(convert(f'blog/{slug}'), version)
+        if not meta.get("summary"):
+            meta["summary"] = plain_text(re.search(r'<p>(.*?)</p>', body, re.DOTALL).group(1))
+        # Tags are used in URLs, eg `/blog/tag/release`.
+        meta["tags"] = [tag.lower().replace(" ", "-") for tag in meta.get("tags", [])]
+
+        compress(f'./src/book/blog/{slug}.html.z', body)
+        posts.append((slug, meta))
+
+    posts.sort(key=lambda post: post[1]["date"], reverse=True)
+
+    with open('./src/book/blog.rs', 'w', encoding="utf8") as f:
+        f.write('/// Newest first.\n')
+        f.write('pub const POSTS: &[super::Post] = &[\n')
+        for slug, meta in posts:
+            tags = ", ".join(rust_str(tag) for tag in meta["tags"])
+            f.write('    super::Post {\n')
+            f.write(f'        slug: "{slug}",\n')
+            for field in BLOG_FIELDS + ["summary"]:
+                f.write(f'        {field}: {rust_str(meta[field])},\n')
+            f.write(f'        tags: &[{tags}],\n')
+            f.write(f'        html: include_bytes!("blog/{slug}.html.z"),\n')
+            f.write('    },\n')
+        f.write('];\n')
+
+    return posts
+
+
+def write_feed(posts):
+    """An Atom feed of the blog, at `/feed.xml`."""
+    updated = posts[0][1]["date"] if posts else "2019-01-01"
+    esc = html.escape
+    with open('./feed.xml', 'w', encoding="utf8") as f:
+        f.write('<?xml version="1.0" encoding="utf-8"?>\n')
+        f.write('<feed xmlns="http://www.w3.org/2005/Atom">\n')
+        f.write('  <title>Seed blog</title>\n')
+        f.write(f'  <link href="{SITE_URL}/blog"/>\n')
+        f.write(f'  <link rel="self" href="{SITE_URL}/feed.xml"/>\n')
+        f.write(f'  <id>{SITE_URL}/blog</id>\n')
+        f.write(f'  <updated>{updated}T00:00:00Z</updated>\n')
+        for slug, meta in posts:
+            url = f'{SITE_URL}/blog/{slug}'
+            f.write('  <entry>\n')
+            f.write(f'    <title>{esc(meta["title"])}</title>\n')
+            f.write(f'    <link href="{url}"/>\n')
+            f.write(f'    <id>{url}</id>\n')
+            f.write(f'    <updated>{meta["date"]}T00:00:00Z</updated>\n')
+            f.write(f'    <author><name>{esc(meta["author"])}</name></author>\n')
+            for tag in meta["tags"]:
+                f.write(f'    <category term="{esc(tag)}"/>\n')
+            f.write(f'    <summary>{esc(meta["summary"])}</summary>\n')
+            f.write('  </entry>\n')
+        f.write('</feed>\n')
+
+
+def write_sitemap(chapters, posts):
+    paths = ["/guide/all", "/examples", "/showcase", "/changelog", "/blog"]
+    paths += [f'/guide/{meta["slug"]}' for _, meta in chapters]
+    paths += [f'/blog/{slug}' for slug, _ in posts]
+
+    with open('./sitemap.xml', 'w', encoding="utf8") as f:
+        f.write('<?xml version="1.0" encoding="utf-8"?>\n')
+        f.write('<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">\n')
+        for path in paths:
+            f.write(f'  <url><loc>{SITE_URL}{path}</loc></url>\n')
+        f.write('</urlset>\n')
+
+
+def write_languages():
+    with open('./src/book/languages.rs', 'w', encoding="utf8") as f:
+        f.write('pub const DEFAULT_LANGUAGE: &str = "' + DEFAULT_LANGUAGE + '";\n\n')
+        f.write('/// (code, name)\n')
+        f.write('pub const LANGUAGES: &[(&str, &str)] = &[\n')
+        for code, name in LANGUAGES.items():
+            f.write(f'    ("{code}", "{name}"),\n')
+        f.write('];\n')
 
 
 def main():
-    filenames = [
-        "quickstart",
-        "prereqs",
-        "structure",
-        "view",
-        "events",
-        "fetch",
-        "routing",
-        "release_and_debugging",
-        "complex_apps",
-        "misc",
-        "server_integration",
-        "about",
-    ]
-    
-    for filename in filenames:
-        # Perform the conversion
-        os.system(f'pandoc ./markdown/{filename}.md -s --highlight-style {STYLE}\
-        -o src/book/{filename}.html --metadata pagetitle="{filename}"')
-
-        # Trim everything except for the HTML body; Pandoc outputs full files.
-        with open(f'./src/book/{filename}.html', encoding="utf8") as f:
-            data = f.read()
-
-        regex = re.compile(r'<body>(.*?)</body>', re.DOTALL)
-        m = re.search(regex, data)
-
-        body = m.groups(0)[0]
-
-        # Update all instances of the version, so we don't have to in Markdown.
-        body = re.sub(r'seed/0\.\d\.(\d{1,3})', "seed/" + VERSION, body)
-
-        # Create a new rust file
-        with open(f'./src/book/{filename}.rs', 'w', encoding="utf8") as f:
-            f.write('pub fn text() -> String {\n')
-            f.write('r#####"')
-            f.write(body)
-            f.write('"#####.into()\n')
-            f.write('}')
-
-        # Clean up the temporary HTML files
-        os.remove(f'./src/book/{filename}.html')
+    version = seed_version()
+    chapters = guide_chapters(version)
+
+    for filename, meta in chapters:
+        translated = [
+            lang for lang in LANGUAGES
+            if lang != DEFAULT_LANGUAGE
+            and os.path.exists(f'./{source_dir(lang)}/{filename}.md')
+        ]
+        translations = {
+            lang: process(convert(filename, lang), version, lang) for lang in translated
+        }
+        titles = {
+            lang: front_matter(f'./{source_dir(lang)}/{filename}.md')[0].get("title", meta["title"])
+            for lang in translated
+        }
+        write_section(
+            filename, process(convert(filename), version), meta["title"], translations, titles
+        )
+    write_sections(chapters)
+
+    posts = write_blog(version)
+    write_feed(posts)
+    write_sitemap(chapters, posts)
+
+    write_languages()
+    write_code_themes()
 
 
 if __name__ == "__main__":
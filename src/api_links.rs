@@ -0,0 +1,82 @@
+//! Links inline code that names a Seed API item, like `Orders` or `attrs!`, to
+//! its page on docs.rs. The items are collected from Seed's source by `build.rs`.
+
+use crate::SEED_VERSION;
+
+// `API_ITEMS`: (name, path under the crate's docs.rs root), eg
+// ("Node", "dom_types/enum.Node.html").
+include!(concat!(env!("OUT_DIR"), "/api_items.rs"));
+
+/// The docs.rs URL for inline code like `Orders`, `attrs!`, `seed::App` or
+/// `App::build`, if it names an API item. Functions are only linked when
+/// qualified, like `seed::update`: bare names like `update` and `view` are far
+/// more often the reader's own.
+fn item_url(code: &str) -> Option<String> {
+    let qualified = code.trim().starts_with("seed::");
+    let code = code.trim().trim_start_matches("seed::");
+    let lookup = |name: &str| {
+        API_ITEMS
+            .iter()
+            .find(|(item, _)| *item == name)
+            .map(|(_, path)| format!("https://docs.rs/seed/{}/seed/{}", SEED_VERSION, path))
+    };
+    let is_fn = |url: &String| url.rsplit('/').next().is_some_and(|page| page.starts_with("fn."));
+
+    if code.ends_with('!') {
+        return lookup(code).filter(|url| url.contains("/macro."));
+    }
+
+    match code.find("::") {
+        // Eg `App::build`, or an enum variant like `Node::Text`.
+        Some(i) => {
+            let (item, member) = (&code[..i], &code[i + 2..]);
+            let valid = !member.is_empty()
+                && member.chars().all(|c| c.is_alphanumeric() || c == '_');
+            if !valid {
+                return None;
+            }
+            let kind = if member.starts_with(char::is_uppercase) {
+                "variant"
+            } else {
+                "method"
+            };
+            lookup(item)
+                .filter(|url| !is_fn(url))
+                .map(|url| format!("{}#{}.{}", url, kind, member))
+        }
+        None => lookup(code).filter(|url| qualified || !is_fn(url)),
+    }
+}
+
+/// Wrap `<code>` spans naming API items in links, leaving code that's already
+/// in a link alone. Code blocks have a class, so aren't matched.
+pub fn crosslink(html: &str) -> String {
+    let mut linked = String::with_capacity(html.len());
+    let mut rest = html;
+    let mut in_link = false;
+
+    while let Some(i) = rest.find('<') {
+        linked.push_str(&rest[..i]);
+        let tag = &rest[i..];
+
+        if tag.starts_with("<a ") || tag.starts_with("<a>") {
+            in_link = true;
+        } else if tag.starts_with("</a>") {
+            in_link = false;
+        } else if tag.starts_with("<code>") && !in_link {
+            let end = tag.find("</code>").unwrap_or(0);
+            let code = &tag["<code>".len()..end.max("<code>".len())];
+            if let Some(url) = item_url(code) {
+                linked.push_str(&format!("<a href=\"{}\"><code>{}</code></a>", url, code));
+                rest = &tag[end + "</code>".len()..];
+                continue;
+            }
+        }
+
+        linked.push('<');
+        rest = &tag[1..];
+    }
+
+    linked.push_str(rest);
+    linked
+}
@@ -0,0 +1,21 @@
+/// Newest first.
+pub const POSTS: &[super::Post] = &[
+    super::Post {
+        slug: "attributes-and-styles",
+        title: "Seed 0.4.1 adds the St enum",
+        date: "2019-08-05",
+        author: "The Seed team",
+        summary: "Style keys get an enum like attributes have, and Node methods are easier to use.",
+        tags: &["release", "view"],
+        html: include_bytes!("blog/attributes-and-styles.html.z"),
+    },
+    super::Post {
+        slug: "orders-is-a-trait",
+        title: "Seed 0.4 makes Orders a trait",
+        date: "2019-07-22",
+        author: "The Seed team",
+        summary: "Version 0.4 changes how update functions ask for follow-up work, and wraps elements in Node.",
+        tags: &["release", "orders"],
+        html: include_bytes!("blog/orders-is-a-trait.html.z"),
+    },
+];
@@ -0,0 +1,9 @@
+pub const DEFAULT_CODE_THEME: &str = "tango";
+
+/// (name, label)
+pub const CODE_THEMES: &[(&str, &str)] = &[
+    ("tango", "Tango"),
+    ("pygments", "Pygments"),
+    ("zenburn", "Zenburn"),
+    ("breezedark", "Breeze Dark"),
+];
@@ -0,0 +1,7 @@
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// (code, name)
+pub const LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("fr", "Français"),
+];
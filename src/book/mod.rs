@@ -1,15 +1,47 @@
 //! Dummy file reuqired by Rust's module system
 //! Markdown-to-HTML provided by https://dillinger.io/
 
-pub mod about;
-pub mod complex_apps;
-pub mod events;
-pub mod fetch;
-pub mod misc;
-pub mod prereqs;
-pub mod quickstart;
-pub mod release_and_debugging;
-pub mod routing;
-pub mod server_integration;
-pub mod structure;
-pub mod view;
+pub mod blog;
+pub mod code_themes;
+pub mod languages;
+
+// A module for each guide chapter, and `SECTIONS`.
+include!("sections.rs");
+
+/// (level, id, text) of a heading in a section; generated alongside its content.
+pub type Heading = (u8, &'static str, &'static str);
+
+/// A guide chapter, from its markdown file's front matter. The functions take a
+/// language, and fall back to English for ones it hasn't been translated to.
+#[derive(Debug)]
+pub struct Section {
+    pub slug: &'static str,  // For routing, eg `/guide/view`.
+    #[allow(dead_code)]  // Only `benches/content.rs` reads it.
+    pub file: &'static str,  // Eg "fetch", for `markdown/fetch.md` and `fetch.html.z`.
+    pub title: fn(&str) -> &'static str,
+    pub text: fn(&str) -> String,  // Decompresses the embedded HTML.
+    pub headings: fn(&str) -> &'static [Heading],
+    pub translations: &'static [&'static str],
+    pub summary: &'static str,
+    pub tags: &'static [&'static str],
+    pub min_seed_version: Option<&'static str>,  // Eg "0.4.0", for features added then.
+}
+
+/// A blog post, from its front matter in `markdown/blog/`.
+pub struct Post {
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub date: &'static str,  // Eg "2019-07-22".
+    pub author: &'static str,
+    pub summary: &'static str,
+    pub tags: &'static [&'static str],
+    pub html: &'static [u8],  // Compressed; see `decompress`.
+}
+
+/// Section HTML is embedded zlib-compressed by `setup_markdown.py`, to keep
+/// the wasm file small.
+pub fn decompress(compressed: &[u8]) -> String {
+    let bytes = miniz_oxide::inflate::decompress_to_vec_zlib(compressed)
+        .expect("Problem decompressing guide content");
+    String::from_utf8(bytes).expect("Guide content isn't valid UTF-8")
+}
@@ -0,0 +1,149 @@
+// Generated by setup_markdown.py, from the guide's front matter.
+
+pub mod about;
+pub mod complex_apps;
+pub mod events;
+pub mod fetch;
+pub mod misc;
+pub mod prereqs;
+pub mod quickstart;
+pub mod release_and_debugging;
+pub mod routing;
+pub mod server_integration;
+pub mod structure;
+pub mod view;
+
+pub const SECTIONS: &[Section] = &[
+    Section {
+        slug: "quickstart",
+        file: "quickstart",
+        title: quickstart::title,
+        text: quickstart::text,
+        headings: quickstart::headings,
+        translations: quickstart::TRANSLATIONS,
+        summary: "Install the tools, and build and serve your first Seed app.",
+        tags: &["setup"],
+        min_seed_version: Some("0.4.0"),
+    },
+    Section {
+        slug: "prereqs",
+        file: "prereqs",
+        title: prereqs::title,
+        text: prereqs::text,
+        headings: prereqs::headings,
+        translations: prereqs::TRANSLATIONS,
+        summary: "What to know about Rust and the web before starting.",
+        tags: &["setup"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "structure",
+        file: "structure",
+        title: structure::title,
+        text: structure::text,
+        headings: structure::headings,
+        translations: structure::TRANSLATIONS,
+        summary: "The model, update function and view that make up every app.",
+        tags: &["architecture"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "view",
+        file: "view",
+        title: view::title,
+        text: view::text,
+        headings: view::headings,
+        translations: view::TRANSLATIONS,
+        summary: "Describing elements, attributes and styles with Seed's macros.",
+        tags: &["view", "macros"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "events",
+        file: "events",
+        title: events::title,
+        text: events::text,
+        headings: events::headings,
+        translations: events::TRANSLATIONS,
+        summary: "Responding to clicks, input and other DOM events with listeners.",
+        tags: &["events"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "http-requests-and-state",
+        file: "fetch",
+        title: fetch::title,
+        text: fetch::text,
+        headings: fetch::headings,
+        translations: fetch::TRANSLATIONS,
+        summary: "Fetching data from a server, and updating the model when it arrives.",
+        tags: &["fetch", "orders"],
+        min_seed_version: Some("0.4.0"),
+    },
+    Section {
+        slug: "routing",
+        file: "routing",
+        title: routing::title,
+        text: routing::text,
+        headings: routing::headings,
+        translations: routing::TRANSLATIONS,
+        summary: "Mapping URLs to pages, and navigating without reloading.",
+        tags: &["routing"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "misc-features",
+        file: "misc",
+        title: misc::title,
+        text: misc::text,
+        headings: misc::headings,
+        translations: misc::TRANSLATIONS,
+        summary: "Logging, local storage, and other utilities.",
+        tags: &["storage"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "release-and-debugging",
+        file: "release_and_debugging",
+        title: release_and_debugging::title,
+        text: release_and_debugging::text,
+        headings: release_and_debugging::headings,
+        translations: release_and_debugging::TRANSLATIONS,
+        summary: "Building optimized wasm, and finding problems in the browser.",
+        tags: &["build", "debugging"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "complex-apps",
+        file: "complex_apps",
+        title: complex_apps::title,
+        text: complex_apps::text,
+        headings: complex_apps::headings,
+        translations: complex_apps::TRANSLATIONS,
+        summary: "Splitting larger apps into modules, and passing messages between them.",
+        tags: &["architecture"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "server-integration",
+        file: "server_integration",
+        title: server_integration::title,
+        text: server_integration::text,
+        headings: server_integration::headings,
+        translations: server_integration::TRANSLATIONS,
+        summary: "Sharing data types between Seed and a Rust backend server.",
+        tags: &["fetch", "backend"],
+        min_seed_version: None,
+    },
+    Section {
+        slug: "about",
+        file: "about",
+        title: about::title,
+        text: about::text,
+        headings: about::headings,
+        translations: about::TRANSLATIONS,
+        summary: "The project's goals, and how Seed compares to other frameworks.",
+        tags: &["about"],
+        min_seed_version: None,
+    },
+];
@@ -0,0 +1,72 @@
+//! Tabbed alternatives in guide content, like `cargo make` and `wasm-pack`
+//! commands; `setup_markdown.py` builds them from code blocks marked eg
+//! ```bash,tab=wasm-pack. Picking a tab shows it in every group that has one
+//! of that name, and is remembered, so readers keep seeing their platform.
+
+use wasm_bindgen::{JsCast, JsValue};
+
+const STORAGE_KEY: &str = "seed-homepage-code-tabs";
+/// How many picked tab names we remember.
+const REMEMBERED: usize = 20;
+
+/// Tab names the reader has picked, most recent first.
+fn preferred() -> Vec<String> {
+    seed::storage::get_storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+        .map(|names| names.lines().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+pub fn select(name: &str) {
+    let mut names = preferred();
+    names.retain(|n| n != name);
+    names.insert(0, name.into());
+    names.truncate(REMEMBERED);
+
+    if let Some(storage) = seed::storage::get_storage() {
+        storage.set_item(STORAGE_KEY, &names.join("\n")).ok();
+    }
+    apply();
+}
+
+/// The elements from a `query_selector_all`.
+fn elements(list: Result<web_sys::NodeList, JsValue>) -> Vec<web_sys::Element> {
+    let list = match list {
+        Ok(list) => list,
+        Err(_) => return Vec::new(),
+    };
+    (0..list.length())
+        .filter_map(|i| list.item(i)?.dyn_into::<web_sys::Element>().ok())
+        .collect()
+}
+
+/// Show each group's most recently picked tab, or its first. Call after
+/// rendering content with tabs.
+pub fn apply() {
+    let preferred = preferred();
+
+    for group in elements(seed::document().query_selector_all(".code-tabs")) {
+        let tabs = elements(group.query_selector_all("[data-tab]"));
+        let names: Vec<String> = tabs
+            .iter()
+            .filter(|t| t.get_attribute("role").is_some_and(|r| r == "tab"))
+            .filter_map(|t| t.get_attribute("data-tab"))
+            .collect();
+        let shown = preferred.iter().find(|p| names.contains(p)).or_else(|| names.first());
+        let shown = match shown {
+            Some(shown) => shown,
+            None => continue,
+        };
+
+        for tab in &tabs {
+            let selected = tab.get_attribute("data-tab").is_some_and(|n| n == *shown);
+            if tab.get_attribute("role").is_some_and(|r| r == "tab") {
+                tab.set_attribute("aria-selected", &selected.to_string()).ok();
+            } else if selected {
+                tab.remove_attribute("hidden").ok();
+            } else {
+                tab.set_attribute("hidden", "").ok();
+            }
+        }
+    }
+}
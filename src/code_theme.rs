@@ -0,0 +1,27 @@
+//! Colours for highlighted code. Pandoc marks tokens with classes, like `kw` for
+//! keywords; `code_themes.css` has a palette for each theme, selected by the
+//! `data-code-theme` attribute on the root element.
+
+use crate::book::code_themes::{CODE_THEMES, DEFAULT_CODE_THEME};
+
+const STORAGE_KEY: &str = "seed-homepage-code-theme";
+
+/// The theme saved from the reader's last choice, if we still have it.
+pub fn preferred() -> String {
+    seed::storage::get_storage()
+        .and_then(|s| s.get_item(STORAGE_KEY).ok()?)
+        .filter(|t| CODE_THEMES.iter().any(|(name, _)| name == t))
+        .unwrap_or_else(|| DEFAULT_CODE_THEME.into())
+}
+
+pub fn save(theme: &str) {
+    if let Some(storage) = seed::storage::get_storage() {
+        storage.set_item(STORAGE_KEY, theme).ok();
+    }
+}
+
+pub fn apply(theme: &str) {
+    if let Some(root) = seed::document().document_element() {
+        root.set_attribute("data-code-theme", theme).ok();
+    }
+}
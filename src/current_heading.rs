@@ -0,0 +1,83 @@
+//! The heading the reader has scrolled to in a guide section, for the
+//! breadcrumbs: the last one that's reached the top third of the window.
+
+use std::cell::RefCell;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Headings in the guide's content, with ids from pandoc.
+const HEADINGS: &str = "#content h2[id], #content h3[id]";
+
+thread_local! {
+    static OBSERVER: RefCell<Option<web_sys::IntersectionObserver>> = const { RefCell::new(None) };
+    /// The observed headings' ids in document order, and whether each has been reached.
+    static REACHED: RefCell<Vec<(String, bool)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn current() -> Option<String> {
+    REACHED.with(|reached| {
+        let reached = reached.borrow();
+        reached.iter().rev().find(|(_, reached)| *reached).map(|(id, _)| id.clone())
+    })
+}
+
+/// Watch for the current heading changing; `on_change` gets its id, or `None`
+/// above the first. Call once, then `observe` after rendering content.
+pub fn listen(on_change: impl Fn(Option<String>) + 'static) {
+    let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+        let before = current();
+
+        REACHED.with(|reached| {
+            let mut reached = reached.borrow_mut();
+            for entry in entries.iter() {
+                let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+                let id = entry.target().id();
+                // In the top third, or scrolled past it.
+                let past = entry.is_intersecting() || entry.bounding_client_rect().top() < 0.;
+                if let Some(heading) = reached.iter_mut().find(|(i, _)| *i == id) {
+                    heading.1 = past;
+                }
+            }
+        });
+
+        let after = current();
+        if after != before {
+            on_change(after);
+        }
+    }) as Box<dyn FnMut(js_sys::Array)>);
+
+    let mut options = web_sys::IntersectionObserverInit::new();
+    options.root_margin("0px 0px -66% 0px");
+    let observer = web_sys::IntersectionObserver::new_with_options(
+        callback.as_ref().unchecked_ref(),
+        &options,
+    );
+    callback.forget();
+
+    OBSERVER.with(|o| *o.borrow_mut() = observer.ok());
+}
+
+/// Watch the headings on the page, instead of those of the last one. Call
+/// after rendering new content.
+pub fn observe() {
+    OBSERVER.with(|observer| {
+        let observer = observer.borrow();
+        let observer = match observer.as_ref() {
+            Some(observer) => observer,
+            None => return,
+        };
+        observer.disconnect();
+
+        let mut headings = Vec::new();
+        if let Ok(list) = seed::document().query_selector_all(HEADINGS) {
+            for heading in (0..list.length()).filter_map(|i| list.item(i)) {
+                if let Ok(heading) = heading.dyn_into::<web_sys::Element>() {
+                    headings.push((heading.id(), false));
+                    observer.observe(&heading);
+                }
+            }
+        }
+        REACHED.with(|r| *r.borrow_mut() = headings);
+    });
+}
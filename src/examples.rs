@@ -0,0 +1,45 @@
+//! The example apps in Seed's repo, collected from `../seed/examples` by `build.rs`.
+
+/// An example crate, eg `todomvc`.
+#[derive(Debug)]
+pub struct Example {
+    pub dir: &'static str,  // Under `examples/` in Seed's repo.
+    pub name: &'static str,
+    pub description: &'static str,
+    pub features: &'static [&'static str],  // Seed features it uses, eg "Routing".
+    pub demo: bool,  // We host a prebuilt copy, in `public/examples/<dir>/`.
+}
+
+impl Example {
+    pub fn source_url(&self) -> String {
+        format!("https://github.com/David-OConnor/seed/tree/master/examples/{}", self.dir)
+    }
+
+    /// A full URL, so the link loads the demo instead of routing within this app.
+    pub fn demo_url(&self) -> String {
+        crate::full_url(&format!("/public/examples/{}/", self.dir))
+    }
+
+    /// Whether this uses `feature`, if one's selected, and its name or
+    /// description contains the search `query`.
+    pub fn matches(&self, feature: Option<&str>, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        feature.is_none_or(|f| self.features.contains(&f))
+            && (self.name.to_lowercase().contains(&query)
+                || self.description.to_lowercase().contains(&query))
+    }
+}
+
+// `EXAMPLES`, sorted by `dir`. Empty if Seed's repo isn't alongside this one.
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+/// Every feature an example uses, for filtering by.
+pub fn features() -> Vec<&'static str> {
+    let mut features: Vec<&str> = EXAMPLES
+        .iter()
+        .flat_map(|e| e.features.iter().cloned())
+        .collect();
+    features.sort();
+    features.dedup();
+    features
+}
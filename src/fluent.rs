@@ -0,0 +1,221 @@
+//! A subset of [Fluent](https://projectfluent.org/fluent/guide/) syntax, for the
+//! UI text catalogs in `locales/`: messages, `{ $variable }` placeables, and
+//! select expressions, which handle plurals. `i18n` picks the catalogs; this is
+//! std-only, so `tests/fluent.rs` can test it natively.
+
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+enum Element {
+    Text(String),
+    Variable(String),
+    Select {
+        variable: String,
+        variants: Vec<(String, Pattern)>,
+        default: usize,
+    },
+}
+
+type Pattern = Vec<Element>;
+
+/// A value passed to a message, eg `{ $count }`.
+#[derive(Clone, Copy, Debug)]
+pub enum Arg<'a> {
+    Str(&'a str),
+    Num(i64),
+}
+
+#[derive(Clone, Debug)]
+pub struct Messages {
+    language: String,
+    messages: HashMap<String, Pattern>,
+    fallback: HashMap<String, Pattern>, // Usually the default language's, for ids missing a translation.
+}
+
+impl Messages {
+    /// Messages in `language` from its catalog's source, falling back to those
+    /// in `fallback` for ids it doesn't define.
+    pub fn from_catalogs(language: &str, source: &str, fallback: &str) -> Self {
+        Self {
+            language: language.into(),
+            messages: parse(source),
+            fallback: parse(fallback),
+        }
+    }
+
+    /// The text for a message that takes no arguments.
+    pub fn get(&self, id: &str) -> String {
+        self.format(id, &[])
+    }
+
+    pub fn format(&self, id: &str, args: &[(&str, Arg)]) -> String {
+        match self.messages.get(id).or_else(|| self.fallback.get(id)) {
+            Some(pattern) => {
+                let mut text = String::new();
+                self.write(pattern, args, &mut text);
+                text
+            }
+            None => id.into(),
+        }
+    }
+
+    fn write(&self, pattern: &[Element], args: &[(&str, Arg)], text: &mut String) {
+        for element in pattern {
+            match element {
+                Element::Text(t) => text.push_str(t),
+                Element::Variable(name) => match arg(args, name) {
+                    Some(Arg::Str(s)) => text.push_str(s),
+                    Some(Arg::Num(n)) => text.push_str(&n.to_string()),
+                    None => text.push_str(&format!("{{${}}}", name)),
+                },
+                Element::Select {
+                    variable,
+                    variants,
+                    default,
+                } => {
+                    let matches = |key: &str| match arg(args, variable) {
+                        Some(Arg::Str(s)) => key == s,
+                        Some(Arg::Num(n)) => {
+                            key == n.to_string() || key == plural_category(&self.language, n)
+                        }
+                        None => false,
+                    };
+                    let variant = variants
+                        .iter()
+                        .find(|(key, _)| matches(key))
+                        .or_else(|| variants.get(*default));
+                    if let Some((_, variant)) = variant {
+                        self.write(variant, args, text);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn arg<'a>(args: &[(&str, Arg<'a>)], name: &str) -> Option<Arg<'a>> {
+    args.iter().find(|(n, _)| *n == name).map(|(_, a)| *a)
+}
+
+/// CLDR plural categories, for the languages we have catalogs for.
+fn plural_category(language: &str, n: i64) -> &'static str {
+    match (language, n) {
+        ("fr", 0) | ("fr", 1) | (_, 1) => "one",
+        _ => "other",
+    }
+}
+
+/// Parse a catalog into patterns by message id. Continuation lines of a message
+/// are indented, or close a select expression; comments start with `#`.
+fn parse(source: &str) -> HashMap<String, Pattern> {
+    let mut messages = HashMap::new();
+    let mut current: Option<(String, String)> = None;
+
+    for line in source.lines() {
+        let continues = (line.starts_with(' ') || line.starts_with('}')) && !line.trim().is_empty();
+        if continues {
+            if let Some((_, value)) = &mut current {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+
+        if let Some((id, value)) = current.take() {
+            messages.insert(id, parse_pattern(&value, &mut 0, false));
+        }
+
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        if let Some(eq) = line.find('=') {
+            current = Some((line[..eq].trim().into(), line[eq + 1..].trim().into()));
+        }
+    }
+    if let Some((id, value)) = current {
+        messages.insert(id, parse_pattern(&value, &mut 0, false));
+    }
+
+    messages
+}
+
+/// Parse text and placeables from `pos`, until the end of the source. In a select
+/// expression's variant, stop at the end of the line or the closing brace instead.
+fn parse_pattern(source: &str, pos: &mut usize, in_variant: bool) -> Pattern {
+    let mut pattern = Vec::new();
+    let mut text = String::new();
+
+    while let Some(c) = source[*pos..].chars().next() {
+        if in_variant && (c == '\n' || c == '}') {
+            break;
+        }
+        *pos += c.len_utf8();
+
+        if c == '{' {
+            if !text.is_empty() {
+                pattern.push(Element::Text(std::mem::take(&mut text)));
+            }
+            pattern.push(parse_placeable(source, pos));
+        } else {
+            text.push(c);
+        }
+    }
+
+    let text = if in_variant { text.trim_end() } else { &text };
+    if !text.is_empty() {
+        pattern.push(Element::Text(text.into()));
+    }
+    pattern
+}
+
+/// Parse a placeable's contents, after its opening brace.
+fn parse_placeable(source: &str, pos: &mut usize) -> Element {
+    let skip_whitespace = |pos: &mut usize| {
+        let rest = &source[*pos..];
+        *pos += rest.len() - rest.trim_start().len();
+    };
+
+    skip_whitespace(pos);
+    let rest = source[*pos..].trim_start_matches('$');
+    let name_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+        .unwrap_or(rest.len());
+    let variable = rest[..name_len].to_string();
+    *pos = source.len() - rest.len() + name_len;
+    skip_whitespace(pos);
+
+    if !source[*pos..].starts_with("->") {
+        *pos += source[*pos..].find('}').map_or(0, |i| i + 1);
+        return Element::Variable(variable);
+    }
+    *pos += 2;
+
+    let mut variants = Vec::new();
+    let mut default = 0;
+    loop {
+        skip_whitespace(pos);
+        let rest = &source[*pos..];
+        if rest.is_empty() || rest.starts_with('}') {
+            *pos += rest.len().min(1);
+            break;
+        }
+        if rest.starts_with('*') {
+            default = variants.len();
+            *pos += 1;
+        }
+        let key_end = source[*pos..].find(']').map_or(source.len(), |i| *pos + i);
+        let key = source[*pos..key_end]
+            .trim_start_matches('[')
+            .trim()
+            .to_string();
+        *pos = (key_end + 1).min(source.len());
+        skip_whitespace(pos);
+        variants.push((key, parse_pattern(source, pos, true)));
+    }
+
+    Element::Select {
+        variable,
+        variants,
+        default,
+    }
+}
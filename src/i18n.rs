@@ -0,0 +1,49 @@
+//! UI text, looked up by id from the catalogs in `locales/`; see `fluent` for
+//! their syntax.
+
+use crate::book::languages::{DEFAULT_LANGUAGE, LANGUAGES};
+pub use crate::fluent::{Arg, Messages};
+
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.ftl")),
+    ("fr", include_str!("../locales/fr.ftl")),
+];
+
+const STORAGE_KEY: &str = "seed-homepage-language";
+
+impl Messages {
+    pub fn new(language: &str) -> Self {
+        Self::from_catalogs(language, catalog(language), catalog(DEFAULT_LANGUAGE))
+    }
+}
+
+fn catalog(language: &str) -> &'static str {
+    CATALOGS
+        .iter()
+        .find(|(code, _)| *code == language)
+        .or_else(|| CATALOGS.iter().find(|(code, _)| *code == DEFAULT_LANGUAGE))
+        .map(|(_, source)| *source)
+        .expect("Missing the default language's catalog")
+}
+
+/// The language saved from the reader's last choice, or else the browser's, if
+/// we have content for it.
+pub fn preferred_language() -> String {
+    let saved = seed::storage::get_storage().and_then(|s| s.get_item(STORAGE_KEY).ok()?);
+    let browser = seed::window()
+        .navigator()
+        .language()
+        .and_then(|l| l.split('-').next().map(str::to_lowercase));
+
+    saved
+        .into_iter()
+        .chain(browser)
+        .find(|l| LANGUAGES.iter().any(|(code, _)| code == l))
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.into())
+}
+
+pub fn save_language(language: &str) {
+    if let Some(storage) = seed::storage::get_storage() {
+        storage.set_item(STORAGE_KEY, language).ok();
+    }
+}
@@ -1,147 +1,799 @@
 //! The Seed homepage - hosting the guide, and acting as an example. Includes
 //! simple interactions, markdown elements, basic routing, and lots of view markup.
 
+mod api_links;
 mod book;
+mod code_tabs;
+mod code_theme;
+mod current_heading;
+mod examples;
+mod fluent;
+mod i18n;
+mod nav;
+mod palette;
+mod permalinks;
+mod scroll;
+mod showcase;
+#[cfg(test)]
+mod snapshots;
+mod theme;
 
 #[macro_use]
 extern crate seed;
 use seed::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use wasm_bindgen::JsCast;
+
+use book::code_themes::CODE_THEMES;
+use book::languages::{DEFAULT_LANGUAGE, LANGUAGES};
+use i18n::{Arg, Messages};
+use theme::THEME;
+
+/// The version of Seed this site is built with; set by `build.rs`.
+const SEED_VERSION: &str = env!("SEED_VERSION");
+const POSTS_PER_PAGE: usize = 5;
+const PALETTE_RESULTS: usize = 10;
 
 // Model
 
 #[derive(Copy, Clone, Debug)]
 enum Page {
     Guide,
+    GuideAll,  // Every section on one page, eg for printing.
+    Examples,
+    Blog,  // The list of posts.
+    BlogPost,
+    Showcase,
     Changelog,
 }
 
-impl ToString for Page {
-    fn to_string(&self) -> String {
+impl fmt::Display for Page {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Eg for url routing
-        match self {
-            Page::Guide => "guide".into(),
-            Page::Changelog => "changelog".into(),
-        }
+        let path = match self {
+            Page::Guide => "guide",
+            Page::GuideAll => "guide/all",
+            Page::Examples => "examples",
+            Page::Blog | Page::BlogPost => "blog",
+            Page::Showcase => "showcase",
+            Page::Changelog => "changelog",
+        };
+        f.write_str(path)
     }
 }
 
 #[derive(Clone, Debug)]
 struct GuideSection {
-    title: String,
-    content: String,
-    path: String,  // For use with routing.
+    section: &'static book::Section,  // Generated from the chapter's markdown.
+    content: HashMap<String, String>,  // By language; filled from `text` the first time it's viewed.
+}
+
+impl GuideSection {
+    fn new(section: &'static book::Section) -> Self {
+        Self {
+            section,
+            content: HashMap::new(),
+        }
+    }
+
+    /// For use with routing.
+    fn path(&self) -> &'static str {
+        self.section.slug
+    }
+
+    fn title(&self, language: &str) -> &'static str {
+        (self.section.title)(self.language(language))
+    }
+
+    /// The language the section is shown in; English if it hasn't been translated.
+    fn language<'a>(&self, language: &'a str) -> &'a str {
+        if self.section.translations.contains(&language) {
+            language
+        } else {
+            DEFAULT_LANGUAGE
+        }
+    }
+
+    /// Decompress the section's HTML, and add links. Stored by the language it's
+    /// viewed in, even if untranslated, since the links and their labels vary.
+    fn load(&mut self, language: &str, messages: &Messages) {
+        if self.content.contains_key(language) {
+            return;
+        }
+
+        let html = api_links::crosslink(&(self.section.text)(self.language(language)));
+        let html = permalinks::add(
+            &html,
+            &localized(language, &format!("/guide/{}", self.path())),
+            &messages.get("heading-link"),
+            &messages.get("heading-copy-link"),
+        );
+        self.content.insert(language.to_string(), html);
+    }
+
+    fn content(&self, language: &str) -> &str {
+        self.content.get(language).map_or("", String::as_str)
+    }
+
+    fn headings(&self, language: &str) -> &'static [book::Heading] {
+        (self.section.headings)(self.language(language))
+    }
 }
 
 
 struct Model {
     page: Page,
-    guide_page: String,  // corresponds to the `path` field of GuidePage
+    language: String,
+    messages: Messages,  // UI text, in `language`.
+    guide_section: usize,  // Index into `guide_sections`; resolved from the route in `navigate`.
+    fragment: Option<String>,  // A heading id in the guide page, from the URL.
+    current_heading: Option<String>,  // The one scrolled to; the fragment's, until we know.
     guide_sections: Vec<GuideSection>,
+    changelog: String,  // HTML, with API names linked to their docs.
+    offline_ready: bool,  // The service worker has precached the site.
+    code_theme: String,  // Colours for highlighted code; see `code_theme`.
+    example_feature: Option<&'static str>,  // Only show examples using this.
+    example_query: String,
+    blog_tag: Option<String>,  // Only list posts with this tag.
+    blog_page: usize,  // Of the list of posts, from 1.
+    blog_post: String,  // The slug of the post shown, eg "orders-is-a-trait".
+    blog_content: HashMap<&'static str, String>,  // By slug; filled the first time it's viewed.
+    showcase_tag: Option<&'static str>,  // Only show projects with this tag.
+    palette: Option<palette::Palette>,  // Open, with what's been typed.
+    shortcuts_open: bool,  // The overlay listing keyboard shortcuts.
+}
+
+impl Model {
+    /// Make sure the selected guide section's content is ready to display.
+    fn load_guide_page(&mut self) {
+        let (language, messages) = (&self.language, &self.messages);
+        if let Some(section) = self.guide_sections.get_mut(self.guide_section) {
+            section.load(language, messages);
+        }
+    }
+
+    fn load_all_guide_pages(&mut self) {
+        for section in &mut self.guide_sections {
+            section.load(&self.language, &self.messages);
+        }
+    }
+
+    /// The selected guide section; `None` only if the guide has no sections.
+    fn current_section(&self) -> Option<&GuideSection> {
+        self.guide_sections.get(self.guide_section)
+    }
+
+    fn previous_section(&self) -> Option<&GuideSection> {
+        let index = self.guide_section.checked_sub(1)?;
+        self.guide_sections.get(index)
+    }
+
+    fn next_section(&self) -> Option<&GuideSection> {
+        self.guide_sections.get(self.guide_section + 1)
+    }
+
+    /// The current route, without a language prefix.
+    fn path(&self) -> String {
+        match self.page {
+            Page::Guide => {
+                format!("/guide/{}", self.current_section().map_or("", GuideSection::path))
+            }
+            Page::Blog => blog_path(self.blog_tag.as_deref(), self.blog_page),
+            Page::BlogPost => format!("/blog/{}", self.blog_post),
+            Page::GuideAll | Page::Examples | Page::Showcase | Page::Changelog => {
+                format!("/{}", self.page)
+            }
+        }
+    }
+}
+
+/// Eg `/blog/tag/release/page/2`.
+fn blog_path(tag: Option<&str>, page: usize) -> String {
+    let mut path = "/blog".to_string();
+    if let Some(tag) = tag {
+        path += &format!("/tag/{}", tag);
+    }
+    if page > 1 {
+        path += &format!("/page/{}", page);
+    }
+    path
+}
+
+/// A full URL for a path on this site. Seed routes links starting with `/`
+/// within the app, so use this for files it doesn't render, like the feed.
+fn full_url(path: &str) -> String {
+    let origin = seed::window().location().origin().unwrap_or_default();
+    format!("{}{}", origin, path)
+}
+
+/// Prefix an internal path with a language, unless it's the default one.
+fn localized(language: &str, path: &str) -> String {
+    if language == DEFAULT_LANGUAGE {
+        path.into()
+    } else {
+        format!("/{}{}", language, path)
+    }
 }
 
 // Setup a default here, for initialization later.
 impl Default for Model {
     fn default() -> Self {
-        let mut guide_sections = Vec::new();
-        let md_texts = vec![
-            ("Quickstart", book::quickstart::text()),
-            ("Prereqs", book::prereqs::text()),
-            ("Structure", book::structure::text()),
-            ("View", book::view::text()),
-            ("Events", book::events::text()),
-            ("Http requests and state", book::fetch::text()),
-            ("Routing", book::routing::text()),
-            ("Misc features", book::misc::text()),
-            (
-                "Release and debugging",
-                book::release_and_debugging::text(),
-            ),
-            ("Complex apps", book::complex_apps::text()),
-            (
-                "Server integration",
-                book::server_integration::text(),
-            ),
-            ("About", book::about::text()),
-        ];
-
-        for (title, md_text) in md_texts {
-            guide_sections.push(GuideSection {
-                title: title.to_string(),
-                content: md_text,
-                path: title.to_lowercase().replace(" ", "-"),
-            });
-        }
+        // Listed in each chapter's front matter; see `setup_markdown.py`.
+        let guide_sections = book::SECTIONS.iter().map(GuideSection::new).collect();
 
-        Self {
+        let language = i18n::preferred_language();
+
+        let mut model = Self {
             page: Page::Guide,
-            guide_page: "quickstart".into(),
+            messages: Messages::new(&language),
+            language,
+            guide_section: 0,
+            fragment: None,
+            current_heading: None,
             guide_sections,
-        }
+            // Seed's CHANGELOG.md, converted by `build.rs`.
+            changelog: api_links::crosslink(include_str!(concat!(
+                env!("OUT_DIR"),
+                "/CHANGELOG.html"
+            ))),
+            offline_ready: false,
+            code_theme: code_theme::preferred(),
+            example_feature: None,
+            example_query: String::new(),
+            blog_tag: None,
+            blog_page: 1,
+            blog_post: String::new(),
+            blog_content: HashMap::new(),
+            showcase_tag: None,
+            palette: None,
+            shortcuts_open: false,
+        };
+        model.load_guide_page();
+        model
     }
 }
 
 #[derive(Clone, Debug)]
 enum Msg {
     ChangePage(Page),
-    ChangeGuidePage(String),
+    ChangeGuidePage(String, Option<String>),  // Section path, and optionally a heading id.
+    ChangeGuideAll(Option<String>),  // Optionally a heading id, with its section's prefix.
+    ChangeBlogIndex(Option<String>, usize),  // Tag, and page number.
+    ChangeBlogPost(String),  // Slug
+    ChangeLanguage(String, Box<Msg>),  // From the route prefix; then handle the rest of the route.
+    OfflineReady,
+    ChangeCodeTheme(String),
+    FilterExamples(Option<&'static str>),
+    SearchExamples(String),
+    FilterShowcase(Option<&'static str>),
+    ContentClick(web_sys::Event),  // Anywhere in guide content; for controls in its HTML.
+    KeyDown(web_sys::KeyboardEvent),  // Anywhere in the page; for shortcuts.
+    OpenPalette,
+    PaletteQuery(String),
+    ClosePalette,  // Also closes the shortcuts overlay.
+    HeadingInView(Option<String>),  // From `current_heading`.
 }
 
 /// The sole source of updating the model; returns a fresh one.
-fn update(msg: Msg, model: &mut Model, _orders: &mut impl Orders<Msg>) {
+fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    // With the language prefix, so switching languages counts as navigating.
+    let (path, fragment) = (localized(&model.language, &model.path()), model.fragment.clone());
+
     match msg {
-        Msg::ChangePage(page) => model.page = page,
-        Msg::ChangeGuidePage(guide_page) => {
+        Msg::ChangePage(_)
+        | Msg::ChangeGuidePage(..)
+        | Msg::ChangeGuideAll(_)
+        | Msg::ChangeBlogIndex(..)
+        | Msg::ChangeBlogPost(_)
+        | Msg::ChangeLanguage(..) => navigate(model, msg),
+        Msg::OfflineReady => model.offline_ready = true,
+        Msg::ChangeCodeTheme(theme) => {
+            code_theme::apply(&theme);
+            code_theme::save(&theme);
+            model.code_theme = theme;
+        }
+        Msg::FilterExamples(feature) => model.example_feature = feature,
+        Msg::SearchExamples(query) => model.example_query = query,
+        Msg::FilterShowcase(tag) => model.showcase_tag = tag,
+        Msg::ContentClick(event) => {
+            orders.skip();
+            let target = event.target().and_then(|t| t.dyn_into::<web_sys::Element>().ok());
+            if let Some(button) = target {
+                if let Some(href) = button.get_attribute("data-copy-href") {
+                    copy_link(&button, &href);
+                }
+                if let Some(tab) = button.get_attribute("data-tab") {
+                    code_tabs::select(&tab);
+                }
+            }
+        }
+        Msg::KeyDown(event) => key_down(&event, model, orders),
+        Msg::OpenPalette => {
+            model.palette = Some(palette::Palette::default());
+            model.shortcuts_open = false;
+            after_render(|| focus_element("palette-input"));
+        }
+        Msg::PaletteQuery(query) => {
+            model.palette = Some(palette::Palette { query, selected: 0 });
+        }
+        Msg::ClosePalette => {
+            model.palette = None;
+            model.shortcuts_open = false;
+        }
+        Msg::HeadingInView(id) => model.current_heading = id,
+    }
+
+    if localized(&model.language, &model.path()) != path || model.fragment != fragment {
+        model.current_heading = model.fragment.clone();
+        let fragment = model.fragment.clone();
+        let offset = scroll::navigated();
+        after_render(move || {
+            code_tabs::apply();
+            focus_content(fragment, offset);
+            current_heading::observe();
+        });
+    }
+}
+
+/// Show the page for a message from `routes`.
+fn navigate(model: &mut Model, msg: Msg) {
+    match msg {
+        Msg::ChangePage(page) => {
+            model.page = page;
+            model.fragment = None;
+        }
+        Msg::ChangeGuidePage(slug, fragment) => {
             model.page = Page::Guide;
-            model.guide_page = guide_page;
+            // An unknown section shows the first, as `/guide` does.
+            model.guide_section =
+                model.guide_sections.iter().position(|s| s.path() == slug).unwrap_or(0);
+            model.fragment = fragment;
+            model.load_guide_page();
+        }
+        Msg::ChangeGuideAll(fragment) => {
+            model.page = Page::GuideAll;
+            model.fragment = fragment;
+            model.load_all_guide_pages();
+        }
+        Msg::ChangeBlogIndex(tag, page) => {
+            model.page = Page::Blog;
+            model.fragment = None;
+            model.blog_tag = tag;
+            model.blog_page = page;
+        }
+        Msg::ChangeBlogPost(slug) => {
+            model.page = Page::BlogPost;
+            model.fragment = None;
+            if let Some(post) = book::blog::POSTS.iter().find(|p| p.slug == slug) {
+                model
+                    .blog_content
+                    .entry(post.slug)
+                    .or_insert_with(|| api_links::crosslink(&book::decompress(post.html)));
+            }
+            model.blog_post = slug;
+        }
+        Msg::ChangeLanguage(language, msg) => {
+            if let Some(root) = seed::document().document_element() {
+                root.set_attribute("lang", &language).ok();
+            }
+            i18n::save_language(&language);
+            model.messages = Messages::new(&language);
+            model.language = language;
+            match model.page {
+                Page::GuideAll => model.load_all_guide_pages(),
+                _ => model.load_guide_page(),
+            }
+            navigate(model, *msg);
+        }
+        _ => (),
+    }
+}
+
+/// Keyboard shortcuts; see `shortcuts`. Keys typed into form fields are left
+/// alone, except for moving through the palette's results.
+fn key_down(event: &web_sys::KeyboardEvent, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let key = event.key();
+
+    if let Some(open) = &model.palette {
+        let commands = palette_commands(model);
+        let results = palette::search(&commands, &open.query, PALETTE_RESULTS);
+        let selected = open.selected.min(results.len().saturating_sub(1));
+
+        match key.as_str() {
+            "Escape" => model.palette = None,
+            "ArrowDown" => {
+                let last = results.len().saturating_sub(1);
+                model.palette = Some(palette::Palette {
+                    query: open.query.clone(),
+                    selected: (selected + 1).min(last),
+                });
+            }
+            "ArrowUp" => {
+                model.palette = Some(palette::Palette {
+                    query: open.query.clone(),
+                    selected: selected.saturating_sub(1),
+                });
+            }
+            "Enter" => {
+                let target = results.get(selected).map(|c| c.target.clone());
+                model.palette = None;
+                if let Some(target) = target {
+                    go_to(target, orders);
+                }
+            }
+            _ => {
+                orders.skip();
+                return;
+            }
+        }
+        event.prevent_default();
+        return;
+    }
+
+    let typing = event
+        .target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|el| {
+            el.is_content_editable()
+                || ["INPUT", "TEXTAREA", "SELECT"].contains(&el.tag_name().as_str())
+        });
+    let command_key = event.ctrl_key() || event.meta_key();
+
+    match key.as_str() {
+        "k" | "K" if command_key => {
+            orders.send_msg(Msg::OpenPalette);
+        }
+        _ if typing || command_key || event.alt_key() => {
+            orders.skip();
+            return;
+        }
+        "/" => {
+            orders.send_msg(Msg::OpenPalette);
+        }
+        "?" => model.shortcuts_open = !model.shortcuts_open,
+        "Escape" if model.shortcuts_open => model.shortcuts_open = false,
+        "j" | "]" => step_guide_section(model, true, orders),
+        "k" | "[" => step_guide_section(model, false, orders),
+        _ => {
+            orders.skip();
+            return;
+        }
+    }
+    event.prevent_default();
+}
+
+/// Go to the next guide section, or the previous one.
+fn step_guide_section(model: &Model, forward: bool, orders: &mut impl Orders<Msg>) {
+    let next = match model.page {
+        Page::Guide if forward => model.next_section(),
+        Page::Guide => model.previous_section(),
+        _ => None,
+    };
+
+    match next {
+        Some(section) => {
+            let path = localized(&model.language, &format!("/guide/{}", section.path()));
+            go_to(palette::Target::Route(path), orders);
+        }
+        None => {
+            orders.skip();
+        }
+    }
+}
+
+/// Follow a palette command, or a shortcut, as if it were a link.
+fn go_to(target: palette::Target, orders: &mut impl Orders<Msg>) {
+    match target {
+        palette::Target::Route(path) => {
+            let url = seed::Url::from(path);
+            scroll::save();  // Before the new entry becomes the current one.
+            seed::push_route(url.clone());
+            if let Some(msg) = routes(url) {
+                orders.send_msg(msg);
+            }
+        }
+        // In a new tab, as the header's links to other sites open.
+        palette::Target::External(url) => {
+            seed::window().open_with_url_and_target(&url, "_blank").ok();
+        }
+    }
+}
+
+/// Copy a heading's full URL, and mark its button so the reader can tell.
+fn copy_link(button: &web_sys::Element, href: &str) {
+    let navigator = seed::window().navigator();
+
+    let copied = js_sys::Reflect::get(&navigator, &"clipboard".into()).and_then(|clipboard| {
+        let write_text: js_sys::Function =
+            js_sys::Reflect::get(&clipboard, &"writeText".into())?.dyn_into()?;
+        write_text.call1(&clipboard, &full_url(href).into())
+    });
+
+    if copied.is_ok() {
+        button.set_attribute("data-copied", "").ok();
+    }
+}
+
+/// Run a function once the next render is done. Seed renders on the next
+/// animation frame, so we wait for the one after.
+fn after_render(f: impl FnOnce() + 'static) {
+    let next_frame = Closure::once_into_js(move || {
+        let f = Closure::once_into_js(f);
+        seed::window().request_animation_frame(f.unchecked_ref()).ok();
+    });
+    seed::window()
+        .request_animation_frame(next_frame.unchecked_ref())
+        .ok();
+}
+
+fn focus_element(id: &str) {
+    let el = seed::document().get_element_by_id(id);
+    if let Some(el) = el.and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()) {
+        el.focus().ok();
+    }
+}
+
+/// Move focus to the new page's heading, or the one in the URL's fragment, so
+/// keyboard and screen reader users continue from the content instead of the
+/// link they followed. Then scroll back to `offset`, when returning to a page;
+/// otherwise to the fragment's heading, or the top.
+fn focus_content(fragment: Option<String>, offset: Option<f64>) {
+    let window = seed::window();
+    let document = seed::document();
+    let heading = fragment.and_then(|id| document.get_element_by_id(&id));
+    let at_heading = offset.is_none() && heading.is_some();
+    let target = heading
+        .or_else(|| document.query_selector("#content h1, #content h2").unwrap_or(None))
+        .or_else(|| document.get_element_by_id("content"));
+
+    if let Some(el) = target.and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()) {
+        el.set_attribute("tabindex", "-1").ok();
+        el.focus().ok();
+        if at_heading {
+            el.scroll_into_view();
         }
     }
+
+    if !at_heading {
+        window.scroll_to_with_x_and_y(0., offset.unwrap_or(0.));
+    }
+}
+
+fn language_switcher(messages: &Messages, language: &str, path: &str) -> Node<Msg> {
+    let links = LANGUAGES.iter().map(|(code, name)| {
+        let mut attrs = attrs! {
+            // Always prefixed; an unprefixed route keeps the current language.
+            At::Href => format!("/{}{}", code, path);
+            At::Custom("hreflang".into()) => code
+        };
+        if *code == language {
+            attrs.add(At::Custom("aria-current".into()), "true");
+        }
+
+        li![a![
+            style! {
+                "margin-left" => THEME.spacing.sm.var();
+                "font-weight" => if *code == language {"bold"} else {"normal"};
+            },
+            attrs,
+            *name
+        ]]
+    });
+
+    nav![
+        attrs! {At::Custom("aria-label".into()) => messages.get("nav-languages-label")},
+        style! {"margin-left" => THEME.spacing.lg.var()},
+        ul![class!["nav-list"], links]
+    ]
 }
 
-fn header(_version: &str) -> Node<Msg> {
+fn code_theme_picker(messages: &Messages, code_theme: &str) -> Node<Msg> {
+    let options = CODE_THEMES.iter().map(|(name, label)| {
+        let mut attrs = attrs! {At::Value => name};
+        if *name == code_theme {
+            attrs.add(At::Selected, "");
+        }
+        option![attrs, *label]
+    });
+
+    label![
+        class!["code-theme-picker"],
+        messages.get("code-theme-label"),
+        select![
+            attrs! {At::Value => code_theme},
+            input_ev(Ev::Change, Msg::ChangeCodeTheme),
+            options
+        ]
+    ]
+}
+
+fn version_badge(version: &str, messages: &Messages, language: &str, path: &str) -> Node<Msg> {
+    let [krate, docs] = &nav::VERSION_BADGE;
+    let link = |link, text| {
+        nav_link(link, Some(text), &style! {}, messages, language, version, path)
+    };
+    span![
+        class!["version-badge"],
+        link(krate, format!("v{}", version)),
+        link(docs, messages.get("nav-docs"))
+    ]
+}
+
+/// A link from `nav`, showing its label, or `text` with the label as its title.
+/// The link to the page we're on is marked, for sighted and screen reader users.
+/// Links that leave the site open in a new tab, and say so.
+fn nav_link(
+    link: &nav::NavLink,
+    text: Option<String>,
+    style: &seed::dom_types::Style,
+    messages: &Messages,
+    language: &str,
+    version: &str,
+    path: &str,
+) -> Node<Msg> {
+    let label = messages.get(link.label);
+    let (text, title) = match text {
+        Some(text) => (text, Some(label)),
+        None => (label, None),
+    };
+
+    let mut attrs = attrs! {At::Href => link.href(language, version)};
+    if link.is_active(path) {
+        attrs.add(At::Class, "active");
+        attrs.add(At::Custom("aria-current".into()), "page");
+    }
+    let external = if link.is_external() {
+        let notice = messages.get("nav-external");
+        let title = match title {
+            Some(title) => format!("{} {}", title, notice),
+            None => notice.clone(),
+        };
+        attrs.add(At::Target, "_blank");
+        attrs.add(At::Rel, "noopener");
+        attrs.add(At::Title, &title);
+        vec![
+            span![
+                class!["external-icon"],
+                attrs! {At::Custom("aria-hidden".into()) => "true"},
+                "↗"
+            ],
+            span![class!["visually-hidden"], notice],
+        ]
+    } else {
+        if let Some(title) = title {
+            attrs.add(At::Title, &title);
+        }
+        Vec::new()
+    };
+    a![style, attrs, text, external]
+}
+
+fn header(
+    version: &str,
+    messages: &Messages,
+    language: &str,
+    path: &str,
+    offline_ready: bool,
+    code_theme: &str,
+) -> Node<Msg> {
     let link_style = style! {
-        "margin-left" => unit!(20, px);
-        "margin-right" => unit!(20, px);
+        "margin-left" => THEME.spacing.lg.var();
+        "margin-right" => THEME.spacing.lg.var();
         "font-weight" => "bold";
-        "font-size" => unit!(1.2, em);
-        "color" => "black";
+        "font-size" => THEME.typography.nav_size.var();
+        "color" => THEME.colors.text.var();
         "cursor" => "pointer";
     };
+    let group_link_style = style! {
+        "display" => "block";
+        "padding" => THEME.spacing.sm.var();
+        "color" => THEME.colors.text.var();
+        "white-space" => "nowrap";
+    };
+
+    let link_to = |link, style| nav_link(link, None, style, messages, language, version, path);
+    let items = nav::NAV.iter().map(|item| match item {
+        nav::NavItem::Link(link) => li![link_to(link, &link_style)],
+        nav::NavItem::Group(label, links) => li![
+            class!["nav-group"],
+            details![
+                summary![&link_style, messages.get(label)],
+                ul![links.iter().map(|link| li![link_to(link, &group_link_style)])]
+            ]
+        ],
+    });
 
     header![
-        style! {"display" => "flex"; "justify-content" => "flex-end"},
-        ul![
-            a![&link_style, "Guide", attrs! {At::Href => "/guide"}],
-            a![&link_style, "Changelog", attrs! {At::Href => "/changelog"}],
-            a![
-                &link_style,
-                "Repo",
-                attrs! {At::Href => "https://github.com/David-OConnor/seed"}
-            ],
-            a![
-                &link_style,
-                "Quickstart repo",
-                attrs! {At::Href => "https://github.com/David-OConnor/seed-quickstart"}
-            ],
-            a![
-                &link_style,
-                "Crate",
-                attrs! {At::Href => "https://crates.io/crates/seed"}
-            ],
-            a![
-                &link_style,
-                "API docs",
-                attrs! {At::Href => "https://docs.rs/seed"}
+        style! {"display" => "flex"; "justify-content" => "flex-end"; "align-items" => "center"},
+        version_badge(version, messages, language, path),
+        nav![
+            attrs! {At::Custom("aria-label".into()) => messages.get("nav-main-label")},
+            ul![class!["nav-list"], items]
+        ],
+        language_switcher(messages, language, path),
+        code_theme_picker(messages, code_theme),
+        if offline_ready {
+            span![
+                class!["offline-indicator"],
+                attrs! {At::Title => messages.get("offline-ready-detail")},
+                messages.get("offline-ready")
             ]
-        ]
+        } else {
+            empty![]
+        },
+    ]
+}
+
+/// Where the reader is, eg Guide › View › SVG, from the route and the heading
+/// scrolled to. Each crumb but the last links to its page.
+fn breadcrumbs(model: &Model) -> Node<Msg> {
+    let (messages, language) = (&model.messages, model.language.as_str());
+    let guide = (messages.get("nav-guide"), "/guide".to_string());
+    let blog = (messages.get("nav-blog"), "/blog".to_string());
+
+    let crumbs: Vec<(String, String)> = match model.page {
+        Page::Guide => {
+            let mut crumbs = vec![guide];
+            if let Some(section) = model.current_section() {
+                let path = format!("/guide/{}", section.path());
+                let heading = model.current_heading.as_ref().and_then(|current| {
+                    section.headings(language).iter().find(|(_, id, _)| *id == current.as_str())
+                });
+
+                crumbs.push((section.title(language).into(), path.clone()));
+                if let Some((_, id, text)) = heading {
+                    crumbs.push((text.to_string(), format!("{}#{}", path, id)));
+                }
+            }
+            crumbs
+        }
+        Page::GuideAll => vec![guide, (messages.get("guide-all-link"), "/guide/all".into())],
+        Page::Examples => vec![(messages.get("nav-examples"), "/examples".into())],
+        Page::Blog => match &model.blog_tag {
+            Some(tag) => vec![
+                blog,
+                (
+                    messages.format("blog-tagged", &[("tag", Arg::Str(tag))]),
+                    blog_path(Some(tag.as_str()), 1),
+                ),
+            ],
+            None => vec![blog],
+        },
+        Page::BlogPost => {
+            let post = book::blog::POSTS.iter().find(|p| p.slug == model.blog_post);
+            let mut crumbs = vec![blog];
+            if let Some(post) = post {
+                crumbs.push((post.title.into(), format!("/blog/{}", post.slug)));
+            }
+            crumbs
+        }
+        Page::Showcase => vec![(messages.get("nav-showcase"), "/showcase".into())],
+        Page::Changelog => vec![(messages.get("nav-changelog"), "/changelog".into())],
+    };
+
+    let last = crumbs.len() - 1;
+    let items = crumbs.into_iter().enumerate().map(|(i, (label, path))| {
+        if i == last {
+            li![span![attrs! {At::Custom("aria-current".into()) => "page"}, label]]
+        } else {
+            li![a![attrs! {At::Href => localized(language, &path)}, label]]
+        }
+    });
+
+    nav![
+        class!["breadcrumbs", "no-print"],
+        attrs! {At::Custom("aria-label".into()) => messages.get("breadcrumbs-label")},
+        ol![items]
     ]
 }
 
-fn title() -> Node<Msg> {
+fn title(messages: &Messages) -> Node<Msg> {
     div![
         style! {
         // todo look up areas
         "display" => "grid";
-        "grid-template-rows" => "auto 160px";
+        "grid-template-rows" => format!("auto {}", THEME.sizes.title_row_height.var());
         "grid-template-columns" => "1fr 1fr 1fr";
         "text-align" => "center";
         "align-items" => "center";
@@ -150,294 +802,833 @@ fn title() -> Node<Msg> {
             style! {"grid-row" => "1/2"; "grid-column" => "1 / 4"},
             img![
                 attrs! {At::Src => "/public/seed_logo.svg"; At::Width => 256; At::Alt => "Seed"},
-                style! {"margin-top" => unit!(30, px)},
+                style! {"margin-top" => THEME.spacing.xl.var()},
             ],
-            h2!["A Rust framework for creating web apps"],
+            h2![messages.get("title-tagline")],
         ],
         div![
             style! {"grid-row" => "2/3"; "grid-column" => "1 / 2"},
-            h2!["Expressive view syntax"]
+            h2![messages.get("title-view-syntax")]
         ],
         div![
             style! {"grid-row" => "2/3"; "grid-column" => "2 / 3"},
-            h2!["Compile-time error checking"]
+            h2![messages.get("title-error-checking")]
         ],
         div![
             style! {"grid-row" => "2/3"; "grid-column" => "3 / 4"},
-            h2!["Clean architecture"]
+            h2![messages.get("title-architecture")]
         ],
     ]
 }
 
-fn guide(sections: &[GuideSection], guide_page: &str) -> Node<Msg> {
+fn guide(model: &Model) -> Node<Msg> {
+    let (messages, language) = (&model.messages, model.language.as_str());
+
     let menu_item_style = style! {
         "display" => "flex";  // So we can vertically center
         "align-items" => "center";
-        "padding" => unit!(4, px);
+        "padding" => THEME.spacing.xs.var();
         "cursor" => "pointer";
-        "height" => unit!(40, px);
+        "height" => THEME.sizes.menu_item_height.var();
         "margin-bottom" => 0;
         "width" => unit!(100, %);
-        "color" => "black";
-        "font-size" => unit!(1.2, em);
+        "color" => THEME.colors.text.var();
+        "font-size" => THEME.typography.nav_size.var();
     };
 
-    let menu_items = sections.iter().map(|s| {
-        h4![
-            &menu_item_style,
+    let menu_items = model.guide_sections.iter().enumerate().map(|(i, s)| {
+        let selected = i == model.guide_section;
+        let mut attrs = attrs! {
+            At::Class => if selected {"guide-menu-selected"} else {"guide-menu"};
+            At::Href => localized(language, &format!("/guide/{}", s.path()));
+            At::Title => s.section.summary
+        };
+        if selected {
+            attrs.add(At::Custom("aria-current".into()), "page");
+        }
+
+        li![a![&menu_item_style, attrs, s.title(language)]]
+    });
+
+    let all_item = li![a![
+        &menu_item_style,
+        attrs! {
+            At::Class => "guide-menu";
+            At::Href => localized(language, "/guide/all")
+        },
+        messages.get("guide-all-link")
+    ]];
+
+    let section = match model.current_section() {
+        Some(section) => section,
+        None => return empty![],
+    };
+
+    let section_link = |target: Option<&GuideSection>, label: &str, rel: &str| match target {
+        Some(s) => a![
             attrs! {
-                At::Class => if s.path == guide_page {"guide-menu-selected"} else {"guide-menu"};
-                At::Href => "/guide/".to_string() + &s.path
+                At::Href => localized(language, &format!("/guide/{}", s.path()));
+                At::Rel => rel
             },
-            s.title
-        ]
-    });
+            messages.format(label, &[("title", Arg::Str(s.title(language)))])
+        ],
+        None => empty![],
+    };
+    let pagination = nav![
+        class!["pagination", "no-print"],
+        attrs! {At::Custom("aria-label".into()) => messages.get("guide-pagination-label")},
+        section_link(model.previous_section(), "guide-previous", "prev"),
+        section_link(model.next_section(), "guide-next", "next"),
+    ];
 
     div![
         style! {
             "display" => "grid";
-            "grid-template-columns" => "200px auto";
-            "color" => "black";
+            "grid-template-columns" => format!("{} auto", THEME.sizes.menu_width.var());
+            "color" => THEME.colors.text.var();
             "grid-auto-rows" => "1fr";
             "align-items" => "start";
         },
-        div![
-            style! {"display" => "flex"; "flex-direction" => "column";
-            "grid-column" => "1 / 2";
-             "justify-content" => "flex-start";
-            "padding" => unit!(10, px);},
-            menu_items
+        nav![
+            class!["no-print"],
+            attrs! {At::Custom("aria-label".into()) => messages.get("nav-guide-label")},
+            style! {"grid-column" => "1 / 2"; "padding" => THEME.spacing.md.var();},
+            ul![
+                style! {"display" => "flex"; "flex-direction" => "column";
+                "justify-content" => "flex-start";
+                "list-style" => "none"; "margin" => 0; "padding" => 0;},
+                menu_items,
+                all_item
+            ]
         ],
         div![
             class!["guide"],
+            attrs! {At::Id => "content"; At::TabIndex => -1},
             style! {
                 "display" => "flex";
                 "flex-direction" => "column";
                 "grid-column" => "2 / 3";
-                "padding" => unit!(80, px);
+                "padding" => THEME.spacing.page.var();
             },
-            raw![&sections.iter().find(|s| s.path == guide_page).unwrap().content],
+            raw_ev(Ev::Click, Msg::ContentClick),
+            translation_notice(section, messages, language),
+            section_details(section.section, messages),
+            raw![section.content(language)],
+            pagination,
+        ]
+    ]
+}
+
+/// A page's main content, in a column, which gets focus on page changes.
+/// Clicks on controls in its HTML, like code tabs, go to `Msg::ContentClick`.
+fn page_content(children: Vec<Node<Msg>>) -> Node<Msg> {
+    div![
+        class!["guide"],
+        attrs! {At::Id => "content"; At::TabIndex => -1},
+        style! {
+            "display" => "flex";
+            "flex-direction" => "column";
+            "padding" => THEME.spacing.page.var();
+        },
+        raw_ev(Ev::Click, Msg::ContentClick),
+        children
+    ]
+}
+
+/// The Seed version a chapter needs, and its tags, from its front matter.
+fn section_details(section: &book::Section, messages: &Messages) -> Node<Msg> {
+    let tags = section.tags.iter().map(|tag| li![format!("#{}", tag)]);
+
+    div![
+        class!["post-details"],
+        match section.min_seed_version {
+            Some(version) => {
+                span![messages.format("guide-requires", &[("version", Arg::Str(version))])]
+            }
+            None => empty![],
+        },
+        ul![
+            class!["post-tags"],
+            attrs! {At::Custom("aria-label".into()) => messages.get("guide-tags-label")},
+            tags
+        ]
+    ]
+}
+
+/// A note that a section is shown in the default language, for want of a
+/// translation.
+fn translation_notice(section: &GuideSection, messages: &Messages, language: &str) -> Node<Msg> {
+    if section.language(language) == language {
+        return empty![];
+    }
+    div![
+        class!["translation-notice"],
+        messages.get("guide-untranslated")
+    ]
+}
+
+/// Give the ids in a section's HTML, and links to them, a prefix; pandoc's ids,
+/// like `cb1` for code blocks, aren't unique once sections are combined.
+fn unique_ids(html: &str, prefix: &str) -> String {
+    html.replace(" id=\"", &format!(" id=\"{}-", prefix))
+        .replace(" href=\"#", &format!(" href=\"#{}-", prefix))
+}
+
+/// Every section in order, with a combined table of contents.
+fn guide_all(sections: &[GuideSection], messages: &Messages, language: &str) -> Node<Msg> {
+    let toc_items = sections.iter().map(|s| {
+        let headings = s
+            .headings(language)
+            .iter()
+            .filter(|(level, _, _)| *level == 2)
+            .map(|(_, id, text)| {
+                li![a![
+                    attrs! {At::Href => format!("#{}-{}", s.path(), id)},
+                    *text
+                ]]
+            });
+
+        li![
+            a![attrs! {At::Href => format!("#{}", s.path())}, s.title(language)],
+            ol![headings]
+        ]
+    });
+
+    let contents = sections.iter().map(|s| {
+        section![
+            attrs! {At::Id => s.path()},
+            translation_notice(s, messages, language),
+            raw![&unique_ids(s.content(language), s.path())]
+        ]
+    });
+
+    let mut children = vec![nav![
+        class!["guide-toc"],
+        attrs! {At::Custom("aria-label".into()) => messages.get("guide-toc-label")},
+        h1![messages.get("guide-all-title")],
+        ol![toc_items]
+    ]];
+    children.extend(contents);
+
+    page_content(children)
+}
+
+fn example_card(example: &examples::Example, messages: &Messages) -> Node<Msg> {
+    let features = example.features.iter().map(|f| li![*f]);
+
+    li![
+        class!["example-card"],
+        h2![example.name],
+        p![example.description],
+        ul![class!["example-features"], features],
+        a![attrs! {At::Href => example.source_url()}, messages.get("examples-source")],
+        if example.demo {
+            a![attrs! {At::Href => example.demo_url()}, messages.get("examples-demo")]
+        } else {
+            empty![]
+        }
+    ]
+}
+
+/// Toggle buttons to show only items with one of `options`, or all of them.
+fn filter_buttons(
+    label: String,
+    all: String,
+    options: Vec<&'static str>,
+    selected: Option<&str>,
+    msg: fn(Option<&'static str>) -> Msg,
+) -> Node<Msg> {
+    let toggle = |text: String, value: Option<&'static str>| {
+        button![
+            attrs! {At::Custom("aria-pressed".into()) => (selected == value).to_string()},
+            simple_ev(Ev::Click, msg(value)),
+            text
+        ]
+    };
+    let toggles = options.into_iter().map(|o| toggle(o.into(), Some(o)));
+
+    div![
+        class!["filters"],
+        attrs! {
+            At::Custom("role".into()) => "group";
+            At::Custom("aria-label".into()) => label
+        },
+        toggle(all, None),
+        toggles
+    ]
+}
+
+/// Cards for Seed's example apps, filtered by a feature they use, or a search.
+fn example_gallery(messages: &Messages, feature: Option<&str>, query: &str) -> Node<Msg> {
+    let cards: Vec<Node<Msg>> = examples::EXAMPLES
+        .iter()
+        .filter(|e| e.matches(feature, query))
+        .map(|e| example_card(e, messages))
+        .collect();
+
+    let results = if examples::EXAMPLES.is_empty() {
+        vec![p![messages.get("examples-none")]]
+    } else if cards.is_empty() {
+        vec![p![messages.get("examples-no-match")]]
+    } else {
+        let count = messages.format("examples-count", &[("count", Arg::Num(cards.len() as i64))]);
+        vec![
+            p![class!["result-count"], attrs! {At::Custom("aria-live".into()) => "polite"}, count],
+            ul![class!["example-cards"], cards],
+        ]
+    };
+
+    let mut children = vec![
+        h1![messages.get("examples-title")],
+        p![messages.get("examples-intro")],
+        input![
+            attrs! {
+                At::Type => "search";
+                At::Value => query;
+                At::Placeholder => messages.get("examples-search");
+                At::Custom("aria-label".into()) => messages.get("examples-search")
+            },
+            input_ev(Ev::Input, Msg::SearchExamples)
+        ],
+        filter_buttons(
+            messages.get("examples-filter-label"),
+            messages.get("examples-all"),
+            examples::features(),
+            feature,
+            Msg::FilterExamples
+        ),
+    ];
+    children.extend(results);
+
+    page_content(children)
+}
+
+fn project_card(project: &showcase::Project, messages: &Messages) -> Node<Msg> {
+    let tags = project.tags.iter().map(|t| li![*t]);
+
+    li![
+        class!["project-card"],
+        match project.screenshot {
+            Some(src) => img![attrs! {
+                At::Src => src;
+                At::Alt => messages.format(
+                    "showcase-screenshot",
+                    &[("name", Arg::Str(project.name))]
+                );
+                At::Custom("loading".into()) => "lazy"
+            }],
+            None => empty![],
+        },
+        h2![a![attrs! {At::Href => project.url}, project.name]],
+        p![project.description],
+        ul![class!["project-tags"], tags],
+        match project.repository {
+            Some(repository) => a![
+                attrs! {At::Href => repository},
+                messages.get("showcase-source")
+            ],
+            None => empty![],
+        }
+    ]
+}
+
+/// Projects built with Seed, optionally only those with a tag.
+fn showcase_page(messages: &Messages, tag: Option<&str>) -> Node<Msg> {
+    let cards = showcase::PROJECTS
+        .iter()
+        .filter(|p| tag.is_none_or(|t| p.tags.contains(&t)))
+        .map(|p| project_card(p, messages));
+
+    page_content(vec![
+        h1![messages.get("showcase-title")],
+        p![messages.get("showcase-intro")],
+        filter_buttons(
+            messages.get("showcase-filter-label"),
+            messages.get("showcase-all"),
+            showcase::tags(),
+            tag,
+            Msg::FilterShowcase
+        ),
+        ul![class!["project-cards"], cards],
+        p![messages.get("showcase-add")],
+    ])
+}
+
+/// A post's date, author and tags, which link to the posts sharing them.
+fn post_details(post: &book::Post, messages: &Messages, language: &str) -> Node<Msg> {
+    let tags = post.tags.iter().map(|tag| {
+        li![a![
+            attrs! {At::Href => localized(language, &blog_path(Some(*tag), 1))},
+            format!("#{}", tag)
+        ]]
+    });
+
+    div![
+        class!["post-details"],
+        span![post.date],
+        span![messages.format("blog-by", &[("author", Arg::Str(post.author))])],
+        ul![
+            class!["post-tags"],
+            attrs! {At::Custom("aria-label".into()) => messages.get("blog-tags-label")},
+            tags
         ]
     ]
 }
 
-fn changelog() -> Node<Msg> {
-    let entries = span![ md!(
-"
-## v0.4.1
-- Added more SVG `At` variants
-- Added the `St` enum, for style keys; similar to `At`
-- Improved ergonomics of `add_child`, `add_attr`, `add_class`,
-`add_style`, `replace_text`, and `add_text`, `Node` methods
-
-## v0.4.0
-- `ElContainer`, imported in prelude, renamed to `View`. (Breaking)
-- Internal refactor of `El`: Now wrapped in `Node`, along with
-`Empty` and `Text`. Creation macros return `Node(Element)`. (Breaking)
-- Changed the way special attributes like `disabled`, `autofocus`, and
-`checked` are handled (Breaking)
-- `MessageMapper` now accepts closures
-- `Orders` is a trait now instead of a struct. (Breaking)
-- Significant changes to MessageMapper
-- Orders has new methods, `clone_app` and `msg_mapper` which can allow access to app instance.
-- Added more SVG element macros
-- Several minor bux fixes
-- Examples updated to reflect these changes
-- Improvements to Fetch API, especially regarding error handling
-and deserialization
-
-## v0.3.7
-- `routes` now accepts `Url` instead of `&Url` (Breaking)
-- Improvements to fetch API
-- Added `raw!`, `md!`, and `plain!` macros that alias `El::from_html`, `El::from_markdown`,
-and `El::new_text` respectively
-- `Attrs!` and `Style!` macros can now use commas and whitespace as separators,
-in addition to semicolons
-- Fixed typos in a few attributes (Breaking)
-- Fixed a bug where an HTML namespace was applied to raw html/markdown elements
-- New conditional syntax added in `class!` macro, similar to `Elm`'s `classList`
-- `Listener` now implements `MessageMapper`
-- `El methods` `add_child`, `add_style`, `add_attr`, and `set_text` now return the elements,
-allowing chaining
-- Fixed a bug with `set_text`. Renamed to `replace_text`. Added `add_text`, which adds
-a text node, but doesn't remove existing ones. Added `add_class`. (Breaking)
-
-## v0.3.6
-- Fetch module and API heavily changed (breaking)
-- Added support for `request​Animation​Frame`, which improves render performance,
-especially for animations
-- Styles no longer implicitly add `px`. Added `unit!` macro in its place
-- `Map` can now be used directly in elements, without needing to annotate type and collect
-(ie for child `Elements`, and `Listener`s)
-- Fixed a bug where `empty` elements at the top-level were rendering in the wrong order
-- Added an `empty!` macro, which is similar to `seed::empty`
-- Attributes and style now retain order
-
-## v0.3.5
-- Fixed a bug where view functions returning `Vec<El>` weren't rendering properly
-- Fixed a typo with the `viewBox` attribute
-
-## v0.3.4
-- The `update` fn now accepts a (new) `Orders` struct, and returns nothing. Renders occur implicitly,
-with the option to skip rendering, update with an additional message, or perform an asynchronous
-action. (Breaking)
-- `.mount()` now accepts elements. Deprecated `.mount_el()`
-- The `log` function and macro now support items which implement `Debug`
-- Removed deprecated `routing::push_path` function (breaking)
-
-## v0.3.3
-- Added `seed::update` function, which allows custom events, and updates from JS.
-
-## v0.3.2
-- Top level view functions can now return `Vec<El<Ms>>`, `El<Ms>`, or something else implementing
-the new ElContainer trait
-
-## v0.3.1
-- Top level view functions now return `Vec<El<Ms>>` instead of `El<Ms>`, mounted directly to
- the mount point. (Breaking)
-- `push_route()` can now accept a `Vec<&str>`, depreciating `push_path()`
-- Fixed a bug where window events couldn't be enabled on initialization
-
-## v0.3.0
-- `update` function now takes a mutable ref of the model. (Breaking)
-- `Update` (update's return type) is now a struct. (Breaking)
-- Async, etc events are now handled through messages, instead of passing `App`
-through the view func. (breaking)
-- Fixed some bugs with empty elements
-- Internal code cleanup
-- Added commented-out release command to example build files
-- Added more tests
-
-## v0.2.10
-- Routing can be triggered by clicking any element containing a `Href` attribute
-with value as a relative link
-- Internal links no longer trigger a page refresh
-- Models no longer need to implement `Clone`
-- Fixed a bug introduced in 0.2.9 for `select` elements
-
-## v0.2.9
-- Added a `RenderThen` option to `Update`, which allows chaining update messages
-- Added a `.model` method to `Update`, allowing for cleaner recursion in updates
-- Improved controlled-comonent (sync fields with model) logic
-
-## v0.2.8
-- Reflowed `El::from_html` and `El::from_markdown` to return `Vec`s of `El`s, instead of wrapping
-them in a single span.
-- Added `set_timeout` wrapper
-- Improved support for SVG and namespaces
-
-## v0.2.7
-- Fixed a bug where `line!` macro interfered with builtin
-- Fixed a bug with routing search (ie `?`)
-
-## v0.2.6
-- Fixed a bug where children would render out-of-order
-- Improved vdom diffing logic
-
-## v0.2.5
-- Attributes and Events now can use `At` and `Ev` enums
-- Routing overhauled; modelled after react-reason. Cleaner syntax, and more flexible
-- Input, Textarea, and Select elements are now \"controlled\" - they always
-stay in sync with the model.
-- index.html file updated in examples and quickstart to use relative paths,
-which fixes landing-page routing
-
-## v0.2.4
-- Changed render func to use a new pattern (Breaking)
-- Default mount point added: \"app\" for element id
-- View func now takes a ref to the model instead of the model itself
-- Routing refactored; now works dynamically
-- Update function now returns an enum that returns Render or Skip,
-to allow conditional rendering (Breaking)
-- Elements can now store more than 1 text node
-
-## V0.2.3
-- Fixed a bug where initially-empty text won't update
-- Added more tests
-- Exposed web_sys Document and Window in top level of Seed create, with .expect
-- Modified build scripts to keep the wasm output name fixed at 'package', simplifying example/quickstart renames
-- Tests now work in Windows due to update in wasm-pack
-
-## V0.2.2
-- Overhaul of fetch module
-- Added server-integration example
-
-## V0.2.1
-- Added support for custom tags
-- Added `class!` and `id!` convenience macros for setting style
-
-## v0.2.0
-
-- Added high-level fetch api
-- Added routing
-- Added element lifecycles (did_mount, did_update, will_unmount)
-- Added support for updating state outside events
-- Added server_interaction, and homepage (this site) examples
-
-## v0.1.0
-
-- Initial release
-
-"
-    ), style! {
-       "grid-column" => "2 / 3";
-    } ];
+/// Posts, newest first, optionally only those with a tag.
+fn blog_index(messages: &Messages, language: &str, tag: Option<&str>, page: usize) -> Node<Msg> {
+    let posts: Vec<&book::Post> = book::blog::POSTS
+        .iter()
+        .filter(|post| tag.is_none_or(|t| post.tags.contains(&t)))
+        .collect();
+    let pages = posts.len().div_ceil(POSTS_PER_PAGE).max(1);
+    let page = page.max(1).min(pages);
+
+    let summaries: Vec<Node<Msg>> = posts
+        .iter()
+        .skip((page - 1) * POSTS_PER_PAGE)
+        .take(POSTS_PER_PAGE)
+        .map(|post| {
+            li![
+                class!["post-summary"],
+                h2![a![
+                    attrs! {At::Href => localized(language, &format!("/blog/{}", post.slug))},
+                    post.title
+                ]],
+                post_details(post, messages, language),
+                p![post.summary]
+            ]
+        })
+        .collect();
+
+    let page_link = |page: usize, text: String| {
+        a![attrs! {At::Href => localized(language, &blog_path(tag, page))}, text]
+    };
+    let pagination = nav![
+        class!["pagination"],
+        attrs! {At::Custom("aria-label".into()) => messages.get("blog-pagination-label")},
+        if page > 1 {
+            page_link(page - 1, messages.get("blog-newer"))
+        } else {
+            empty![]
+        },
+        span![messages.format(
+            "blog-page",
+            &[("page", Arg::Num(page as i64)), ("pages", Arg::Num(pages as i64))]
+        )],
+        if page < pages {
+            page_link(page + 1, messages.get("blog-older"))
+        } else {
+            empty![]
+        }
+    ];
+
+    page_content(vec![
+        h1![match tag {
+            Some(tag) => messages.format("blog-tagged", &[("tag", Arg::Str(tag))]),
+            None => messages.get("blog-title"),
+        }],
+        if tag.is_some() {
+            a![
+                attrs! {At::Href => localized(language, "/blog")},
+                messages.get("blog-all-posts")
+            ]
+        } else {
+            a![attrs! {At::Href => full_url("/feed.xml")}, messages.get("blog-feed")]
+        },
+        if summaries.is_empty() {
+            p![messages.get("blog-none")]
+        } else {
+            ul![class!["post-list"], summaries]
+        },
+        pagination,
+    ])
+}
+
+fn blog_post(
+    slug: &str,
+    content: Option<&String>,
+    messages: &Messages,
+    language: &str,
+) -> Node<Msg> {
+    let post = book::blog::POSTS.iter().find(|p| p.slug == slug);
+
+    page_content(vec![
+        a![
+            attrs! {At::Href => localized(language, "/blog")},
+            messages.get("blog-all-posts")
+        ],
+        match (post, content) {
+            (Some(post), Some(content)) => article![
+                h1![post.title],
+                post_details(post, messages, language),
+                raw![content]
+            ],
+            _ => p![messages.get("blog-not-found")],
+        },
+    ])
+}
+
+fn changelog(html: &str) -> Node<Msg> {
+    let entries = span![
+        raw![html],
+        style! {
+           "grid-column" => "2 / 3";
+        }
+    ];
 
     div![
         class!["guide"],
+        attrs! {At::Id => "content"; At::TabIndex => -1},
         style! {
             "display" => "grid";
             "grid-template-columns" => "1fr 2fr 1fr";
 
-            "padding" => unit!(50, px);
-            "color" => "black";
+            "padding" => THEME.spacing.section.var();
+            "color" => THEME.colors.text.var();
         },
         entries,
     ]
 }
 
-fn footer() -> Node<Msg> {
+/// Everything the palette can go to: the header's pages, guide sections and
+/// their headings, then the header's external links.
+fn palette_commands(model: &Model) -> Vec<palette::Command> {
+    let (messages, language) = (&model.messages, model.language.as_str());
+    let command = |label: String, kind: &str, target| palette::Command {
+        label,
+        kind: messages.get(kind),
+        target,
+    };
+    let route = |path: &str| palette::Target::Route(localized(language, path));
+    let nav_command = |link: &nav::NavLink| {
+        let href = link.href(language, SEED_VERSION);
+        let (kind, target) = if link.is_external() {
+            ("palette-kind-external", palette::Target::External(href))
+        } else {
+            ("palette-kind-page", palette::Target::Route(href))
+        };
+        command(messages.get(link.label), kind, target)
+    };
+
+    let mut commands: Vec<_> = nav::links().filter(|l| !l.is_external()).map(nav_command).collect();
+    let guide_all = route("/guide/all");
+    commands.push(command(messages.get("guide-all-link"), "palette-kind-page", guide_all));
+
+    for section in &model.guide_sections {
+        let path = format!("/guide/{}", section.path());
+        let title = section.title(language);
+        commands.push(command(title.into(), "palette-kind-section", route(&path)));
+
+        for (_, id, text) in section.headings(language).iter().filter(|(level, ..)| *level > 1) {
+            commands.push(command(
+                format!("{} › {}", title, text),
+                "palette-kind-heading",
+                route(&format!("{}#{}", path, id)),
+            ));
+        }
+    }
+
+    commands.extend(nav::links().filter(|l| l.is_external()).map(nav_command));
+    commands
+}
+
+/// A search box over `palette_commands`. The arrow keys and Enter are handled
+/// in `key_down`; results are links, so they can be clicked too.
+fn palette_view(
+    open: &palette::Palette,
+    commands: &[palette::Command],
+    messages: &Messages,
+) -> Node<Msg> {
+    let results = palette::search(commands, &open.query, PALETTE_RESULTS);
+    let selected = open.selected.min(results.len().saturating_sub(1));
+
+    let items: Vec<Node<Msg>> = results
+        .iter()
+        .enumerate()
+        .map(|(i, command)| {
+            let href = match &command.target {
+                palette::Target::Route(path) => path.as_str(),
+                palette::Target::External(url) => url.as_str(),
+            };
+            let mut link_attrs = attrs! {
+                At::Href => href;
+                At::TabIndex => -1;
+                At::Custom("data-kind".into()) => command.kind;
+            };
+            if let palette::Target::External(_) = command.target {
+                link_attrs.add(At::Target, "_blank");
+                link_attrs.add(At::Rel, "noopener");
+            }
+            // The kind is shown with CSS, so clicks always land on the link itself,
+            // which Seed needs to route it.
+            li![
+                attrs! {
+                    At::Id => format!("palette-result-{}", i);
+                    At::Custom("role".into()) => "option";
+                    At::Custom("aria-selected".into()) => (i == selected).to_string();
+                },
+                a![
+                    link_attrs,
+                    simple_ev(Ev::Click, Msg::ClosePalette),
+                    command.label
+                ]
+            ]
+        })
+        .collect();
+
+    let mut input_attrs = attrs! {
+        At::Id => "palette-input";
+        At::Type => "text";
+        At::Value => open.query;
+        At::Placeholder => messages.get("palette-placeholder");
+        At::AutoComplete => "off";
+        At::Custom("role".into()) => "combobox";
+        At::Custom("aria-expanded".into()) => "true";
+        At::Custom("aria-controls".into()) => "palette-results";
+        At::Custom("aria-label".into()) => messages.get("palette-label");
+    };
+    if !results.is_empty() {
+        input_attrs.add(
+            At::Custom("aria-activedescendant".into()),
+            format!("palette-result-{}", selected),
+        );
+    }
+
+    div![
+        div![class!["dialog-backdrop"], simple_ev(Ev::Click, Msg::ClosePalette)],
+        div![
+            class!["dialog", "palette"],
+            attrs! {
+                At::Custom("role".into()) => "dialog";
+                At::Custom("aria-modal".into()) => "true";
+                At::Custom("aria-label".into()) => messages.get("palette-label");
+            },
+            input![input_attrs, input_ev(Ev::Input, Msg::PaletteQuery)],
+            if items.is_empty() {
+                p![class!["palette-empty"], messages.get("palette-no-results")]
+            } else {
+                ul![
+                    attrs! {
+                        At::Id => "palette-results";
+                        At::Custom("role".into()) => "listbox";
+                    },
+                    items
+                ]
+            }
+        ]
+    ]
+}
+
+/// The keyboard shortcuts `key_down` handles, opened with `?`.
+fn shortcuts(messages: &Messages) -> Node<Msg> {
+    let rows = [
+        (&["/", "Ctrl K"][..], "shortcut-palette"),
+        (&["j", "]"][..], "shortcut-next"),
+        (&["k", "["][..], "shortcut-previous"),
+        (&["?"][..], "shortcut-help"),
+        (&["Esc"][..], "shortcut-close"),
+    ];
+
+    div![
+        div![class!["dialog-backdrop"], simple_ev(Ev::Click, Msg::ClosePalette)],
+        div![
+            class!["dialog", "shortcuts"],
+            attrs! {
+                At::Custom("role".into()) => "dialog";
+                At::Custom("aria-modal".into()) => "true";
+                At::Custom("aria-labelledby".into()) => "shortcuts-title";
+            },
+            h2![attrs! {At::Id => "shortcuts-title"}, messages.get("shortcuts-title")],
+            dl![rows.iter().map(|(keys, description)| {
+                div![
+                    dt![keys.iter().map(|key| kbd![*key])],
+                    dd![messages.get(description)]
+                ]
+            })],
+            button![simple_ev(Ev::Click, Msg::ClosePalette), messages.get("shortcut-close")]
+        ]
+    ]
+}
+
+fn footer(messages: &Messages) -> Node<Msg> {
     footer![
         style! {"display" => "flex"; "justify-content" => "center"},
-        h4!["© 2019 David O'Connor"]
+        h4![messages.format("footer-copyright", &[("year", Arg::Num(2019))])]
     ]
 }
 
 fn view(model: &Model) -> Node<Msg> {
-    let version = "0.3.1";
     div![
         style! {
             "display" => "flex";
             "flex-direction" => "column";
         },
-        section![header(version)],
-        section![title()],
-        section![match model.page {
-            Page::Guide => guide(&model.guide_sections, &model.guide_page),
-            Page::Changelog => changelog(),
+        a![
+            class!["skip-link"],
+            attrs! {At::Href => "#content"},
+            model.messages.get("skip-to-content")
+        ],
+        section![
+            class!["no-print"],
+            header(
+                SEED_VERSION,
+                &model.messages,
+                &model.language,
+                &model.path(),
+                model.offline_ready,
+                &model.code_theme
+            )
+        ],
+        section![class!["no-print"], title(&model.messages)],
+        breadcrumbs(model),
+        main![match model.page {
+            Page::Guide => guide(model),
+            Page::GuideAll => guide_all(&model.guide_sections, &model.messages, &model.language),
+            Page::Examples => example_gallery(
+                &model.messages,
+                model.example_feature,
+                &model.example_query
+            ),
+            Page::Blog => blog_index(
+                &model.messages,
+                &model.language,
+                model.blog_tag.as_deref(),
+                model.blog_page
+            ),
+            Page::BlogPost => blog_post(
+                &model.blog_post,
+                model.blog_content.get(model.blog_post.as_str()),
+                &model.messages,
+                &model.language
+            ),
+            Page::Showcase => showcase_page(&model.messages, model.showcase_tag),
+            Page::Changelog => changelog(&model.changelog),
         }],
-        section![footer()],
+        section![footer(&model.messages)],
+        match &model.palette {
+            Some(open) => palette_view(open, &palette_commands(model), &model.messages),
+            None => empty![],
+        },
+        if model.shortcuts_open {
+            shortcuts(&model.messages)
+        } else {
+            empty![]
+        },
     ]
 }
 
+fn window_events(_: &Model) -> Vec<seed::events::Listener<Msg>> {
+    vec![keyboard_ev(Ev::KeyDown, Msg::KeyDown)]
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn routes(url: seed::Url) -> Option<Msg> {
-    Some(match url.path.get(0).map(String::as_str) {
-        Some("guide") => match url.path.get(1).as_ref() {
-            Some(page) => Msg::ChangeGuidePage(page.to_string()),
+    // Eg `/guide/view#the-node-enum`. Links we follow keep the fragment in the
+    // last path segment, rather than in `hash`.
+    let mut fragment = url.hash.clone();
+    let mut segments = Vec::new();
+    for segment in &url.path {
+        let mut parts = segment.splitn(2, '#');
+        segments.push(parts.next().unwrap_or_default().to_string());
+        if let Some(f) = parts.next() {
+            fragment = Some(f.into());
+        }
+    }
+    let fragment = fragment
+        .map(|f| f.trim_start_matches('#').to_string())
+        .filter(|f| !f.is_empty());
+
+    // Eg `/fr/guide/view`. Without a prefix, we keep the current language.
+    let mut path = segments.as_slice();
+    let language = match path.first() {
+        Some(lang) if LANGUAGES.iter().any(|(code, _)| code == lang) => {
+            path = &path[1..];
+            Some(lang.clone())
+        }
+        _ => None,
+    };
+
+    let msg = match path.first().map(String::as_str) {
+        Some("guide") => match path.get(1).map(String::as_str) {
+            Some("all") => Msg::ChangeGuideAll(fragment),
+            Some(page) => Msg::ChangeGuidePage(page.to_string(), fragment),
             None => Msg::ChangePage(Page::Guide),
         },
+        Some("examples") => Msg::ChangePage(Page::Examples),
+        Some("showcase") => Msg::ChangePage(Page::Showcase),
+        Some("blog") => {
+            let rest: Vec<&str> = path[1..].iter().map(String::as_str).collect();
+            let number = |n: &str| n.parse().unwrap_or(1);
+            match rest.as_slice() {
+                ["page", n] => Msg::ChangeBlogIndex(None, number(n)),
+                ["tag", tag] => Msg::ChangeBlogIndex(Some(tag.to_string()), 1),
+                ["tag", tag, "page", n] => Msg::ChangeBlogIndex(Some(tag.to_string()), number(n)),
+                [slug] => Msg::ChangeBlogPost(slug.to_string()),
+                _ => Msg::ChangeBlogIndex(None, 1),
+            }
+        }
         Some("changelog") => Msg::ChangePage(Page::Changelog),
         _ => Msg::ChangePage(Page::Guide),
+    };
+
+    Some(match language {
+        Some(language) => Msg::ChangeLanguage(language, Box::new(msg)),
+        None => msg,
     })
 }
 
 
+/// Register `sw.js`, which precaches the site, and let the reader know once
+/// it's available offline. Release builds only, so development isn't served
+/// stale files.
+fn register_service_worker(app: seed::App<Msg, Model, Node<Msg>>) {
+    let navigator = seed::window().navigator();
+    let supported = js_sys::Reflect::has(&navigator, &"serviceWorker".into()).unwrap_or(false);
+    if cfg!(debug_assertions) || !supported {
+        return;
+    }
+
+    let container = navigator.service_worker();
+    // Registration failing only leaves the site online-only.
+    let _ = container.register("/sw.js");
+
+    // Resolves once a worker is active, which is after it's finished precaching.
+    if let Ok(ready) = container.ready() {
+        let on_ready = Closure::once(move |_: JsValue| app.update(Msg::OfflineReady));
+        let _ = ready.then(&on_ready);
+        on_ready.forget();
+    }
+}
+
 #[wasm_bindgen(start)]
 pub fn render() {
-        seed::App::build(|_, _| Init::new(Model::default()), update, view)
+        scroll::listen();
+
+        let app = seed::App::build(
+            |_, _| {
+                let model = Model::default();
+                code_theme::apply(&model.code_theme);
+                after_render(|| {
+                    code_tabs::apply();
+                    current_heading::observe();
+                    // After a reload on a route that doesn't change the page, so
+                    // `update` doesn't restore it.
+                    if let Some(offset) = scroll::saved() {
+                        seed::window().scroll_to_with_x_and_y(0., offset);
+                    }
+                });
+                Init::new(model)
+            },
+            update,
+            view,
+        )
 
         .routes(routes)
-        .finish()
-        .run();
+        .window_events(window_events)
+        .build_and_start();
+
+        let on_heading = app.clone();
+        current_heading::listen(move |id| on_heading.update(Msg::HeadingInView(id)));
+        register_service_worker(app);
 }
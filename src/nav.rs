@@ -0,0 +1,103 @@
+//! The header's navigation: pages on this site, and links elsewhere grouped in
+//! dropdowns. `header` renders it, and the command palette offers the same
+//! links.
+
+#[derive(Clone, Copy, Debug)]
+pub enum Target {
+    /// A route, without a language prefix; also active on routes below it.
+    Internal(&'static str),
+    /// A URL on another site; `{version}` is replaced with Seed's version.
+    External(&'static str),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NavLink {
+    pub label: &'static str,  // Message id.
+    pub target: Target,
+}
+
+impl NavLink {
+    const fn internal(label: &'static str, route: &'static str) -> Self {
+        Self {
+            label,
+            target: Target::Internal(route),
+        }
+    }
+
+    const fn external(label: &'static str, url: &'static str) -> Self {
+        Self {
+            label,
+            target: Target::External(url),
+        }
+    }
+
+    /// External links open in a new tab.
+    pub fn is_external(&self) -> bool {
+        match self.target {
+            Target::Internal(_) => false,
+            Target::External(_) => true,
+        }
+    }
+
+    /// Whether this links to the page at `path`, a route without a language
+    /// prefix, or to one it's part of; eg the guide, for `/guide/view`.
+    pub fn is_active(&self, path: &str) -> bool {
+        match self.target {
+            Target::Internal(route) => {
+                path == route || (path.starts_with(route) && path[route.len()..].starts_with('/'))
+            }
+            Target::External(_) => false,
+        }
+    }
+
+    /// Where the link goes, for the current language and Seed version.
+    pub fn href(&self, language: &str, version: &str) -> String {
+        match self.target {
+            Target::Internal(route) => crate::localized(language, route),
+            Target::External(url) => url.replace("{version}", version),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum NavItem {
+    Link(NavLink),
+    Group(&'static str, &'static [NavLink]),  // A dropdown, with its label's message id.
+}
+
+const CRATE_URL: &str = "https://crates.io/crates/seed/{version}";
+const API_DOCS_URL: &str = "https://docs.rs/seed/{version}";
+
+pub const NAV: &[NavItem] = &[
+    NavItem::Link(NavLink::internal("nav-guide", "/guide")),
+    NavItem::Link(NavLink::internal("nav-examples", "/examples")),
+    NavItem::Link(NavLink::internal("nav-blog", "/blog")),
+    NavItem::Link(NavLink::internal("nav-showcase", "/showcase")),
+    NavItem::Link(NavLink::internal("nav-changelog", "/changelog")),
+    NavItem::Group(
+        "nav-resources",
+        &[
+            NavLink::external("nav-repo", "https://github.com/David-OConnor/seed"),
+            NavLink::external(
+                "nav-quickstart-repo",
+                "https://github.com/David-OConnor/seed-quickstart",
+            ),
+            NavLink::external("nav-crate", CRATE_URL),
+            NavLink::external("nav-api-docs", API_DOCS_URL),
+        ],
+    ),
+];
+
+/// The header's version badge: the crate, then its docs; labels are titles.
+pub const VERSION_BADGE: [NavLink; 2] = [
+    NavLink::external("version-crate", CRATE_URL),
+    NavLink::external("version-docs", API_DOCS_URL),
+];
+
+/// Every link in `NAV`, in order, including those in groups.
+pub fn links() -> impl Iterator<Item = &'static NavLink> {
+    NAV.iter().flat_map(|item| match item {
+        NavItem::Link(link) => std::slice::from_ref(link),
+        NavItem::Group(_, links) => *links,
+    })
+}
@@ -0,0 +1,66 @@
+//! The command palette: fuzzy search over pages, guide sections and their headings.
+
+/// Where a command goes.
+#[derive(Clone, Debug)]
+pub enum Target {
+    Route(String),  // A path in this app, eg `/guide/view#the-node-enum`.
+    External(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub label: String,
+    pub kind: String,  // Shown beside the label, eg "Guide" or "External link".
+    pub target: Target,
+}
+
+/// How well `query` matches `text`, if it does: its characters must appear in
+/// order, ignoring case. Matches at the start of words, and runs of consecutive
+/// characters, score higher; so do shorter texts, which are closer matches.
+pub fn score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut previous: Option<usize> = None;
+
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = pos + text[pos..].iter().position(|t| *t == c)?;
+
+        score += 1;
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 4;
+        }
+        previous = Some(found);
+        pos = found + 1;
+    }
+
+    Some(score * 100 - text.len() as i64)
+}
+
+/// Up to `limit` commands matching `query`, best first; or the first ones, in
+/// order, for an empty query.
+pub fn search<'a>(commands: &'a [Command], query: &str, limit: usize) -> Vec<&'a Command> {
+    if query.trim().is_empty() {
+        return commands.iter().take(limit).collect();
+    }
+
+    let mut matches: Vec<(i64, usize, &Command)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(i, command)| Some((score(query, &command.label)?, i, command)))
+        .collect();
+
+    // Commands that score the same stay in order.
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    matches.into_iter().take(limit).map(|(_, _, command)| command).collect()
+}
+
+/// The open palette.
+#[derive(Clone, Debug, Default)]
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,  // Index in the results.
+}
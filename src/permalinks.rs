@@ -0,0 +1,60 @@
+//! Adds a "¶" permalink and a copy-link button to each h2 and h3 in a guide
+//! section, using the ids pandoc gives headings.
+
+/// The id of a heading's opening tag, like `<h2 id="the-node-enum">`.
+fn heading_id(heading: &str) -> Option<&str> {
+    let value = &heading[heading.find(" id=\"")? + " id=\"".len()..];
+    let tag_end = heading.find('>')?;
+    let end = value.find('"')?;
+
+    if heading.len() - value.len() + end < tag_end {
+        Some(&value[..end])
+    } else {
+        None
+    }
+}
+
+/// `base` is the section's route, eg `/guide/view`. Copy buttons carry their link
+/// in `data-copy-href`; clicks on them are handled in `update`.
+pub fn add(html: &str, base: &str, link_label: &str, copy_label: &str) -> String {
+    let mut linked = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<h") {
+        linked.push_str(&rest[..start]);
+        let heading = &rest[start..];
+
+        let level = match heading.as_bytes().get(2) {
+            Some(b'2') => "2",
+            Some(b'3') => "3",
+            _ => "",
+        };
+        let close = format!("</h{}>", level);
+
+        match (level, heading_id(heading), heading.find(&close)) {
+            ("2", Some(id), Some(end)) | ("3", Some(id), Some(end)) => {
+                let href = format!("{}#{}", base, id);
+                linked.push_str(&heading[..end]);
+                linked.push_str(&format!(
+                    concat!(
+                        "<a class=\"heading-anchor\" href=\"{href}\" aria-label=\"{link}\">¶</a>",
+                        "<button class=\"copy-link\" data-copy-href=\"{href}\" ",
+                        "aria-label=\"{copy}\" title=\"{copy}\">⎘</button>",
+                    ),
+                    href = href,
+                    link = link_label,
+                    copy = copy_label,
+                ));
+                linked.push_str(&close);
+                rest = &heading[end + close.len()..];
+            }
+            _ => {
+                linked.push_str("<h");
+                rest = &heading[2..];
+            }
+        }
+    }
+
+    linked.push_str(rest);
+    linked
+}
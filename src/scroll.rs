@@ -0,0 +1,132 @@
+//! Scroll positions across navigation. Following a link starts at the top of the
+//! new page, or at the heading it links to; going back or forward, by any number
+//! of entries, or reloading, returns to where the reader was on that page.
+//!
+//! Each history entry keeps its offset in its state, next to the route Seed keeps
+//! there as a JSON string; Seed ignores fields it doesn't know.
+
+use std::cell::Cell;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Where in an entry's state its offset is.
+const OFFSET_KEY: &str = "scrollY";
+/// How long scrolling has to pause before we save the offset; browsers limit how
+/// often state can be replaced.
+const SAVE_DELAY: i32 = 150;  // ms
+
+thread_local! {
+    /// Set by `popstate`, for the route change that follows it. Also set for the
+    /// first route, so a reload returns to where the reader was.
+    static POPPED: Cell<bool> = const { Cell::new(true) };
+    /// The timeout that will save the offset, while the reader is scrolling.
+    static PENDING_SAVE: Cell<Option<i32>> = const { Cell::new(None) };
+}
+
+/// Take over restoring scroll positions from the browser, which would do it
+/// before we've rendered the page. Call before starting the app, so our
+/// `popstate` and `click` listeners run before Seed's, which route.
+pub fn listen() {
+    let window = seed::window();
+    if let Ok(history) = window.history() {
+        history.set_scroll_restoration(web_sys::ScrollRestoration::Manual).ok();
+    }
+
+    let on_pop = Closure::wrap(Box::new(|| POPPED.with(|p| p.set(true))) as Box<dyn FnMut()>);
+    window
+        .add_event_listener_with_callback("popstate", on_pop.as_ref().unchecked_ref())
+        .ok();
+    on_pop.forget();
+
+    let save_later = Closure::wrap(Box::new(|| {
+        let save = Closure::once_into_js(save);
+        let timeout = seed::window().set_timeout_with_callback_and_timeout_and_arguments_0(
+            save.unchecked_ref(),
+            SAVE_DELAY,
+        );
+        if let Some(previous) = PENDING_SAVE.with(|p| p.replace(timeout.ok())) {
+            seed::window().clear_timeout_with_handle(previous);
+        }
+    }) as Box<dyn FnMut()>);
+    window
+        .add_event_listener_with_callback("scroll", save_later.as_ref().unchecked_ref())
+        .ok();
+    save_later.forget();
+
+    // A link may be followed before a save is due; save first, while this page's
+    // entry is still the current one.
+    let save_now = Closure::wrap(Box::new(|| {
+        if PENDING_SAVE.with(Cell::get).is_some() {
+            save();
+        }
+    }) as Box<dyn FnMut()>);
+    window
+        .add_event_listener_with_callback_and_bool(
+            "click",
+            save_now.as_ref().unchecked_ref(),
+            true,  // Capture, before links' own listeners.
+        )
+        .ok();
+    save_now.forget();
+}
+
+/// An entry's state, as an object we can add to.
+fn state_object(state: &JsValue) -> js_sys::Object {
+    let parsed = match state.as_string() {
+        Some(json) => js_sys::JSON::parse(&json).unwrap_or(JsValue::NULL),
+        None => state.clone(),
+    };
+    let object = js_sys::Object::new();
+    if parsed.is_object() {
+        js_sys::Object::assign(&object, parsed.unchecked_ref());
+    }
+    object
+}
+
+/// Record how far down the current entry's page is scrolled.
+pub fn save() {
+    if let Some(pending) = PENDING_SAVE.with(|p| p.replace(None)) {
+        seed::window().clear_timeout_with_handle(pending);
+    }
+
+    let window = seed::window();
+    let history = match window.history() {
+        Ok(history) => history,
+        Err(_) => return,
+    };
+    let state = history.state().unwrap_or(JsValue::NULL);
+    let object = state_object(&state);
+    let offset = window.scroll_y().unwrap_or(0.);
+    js_sys::Reflect::set(&object, &OFFSET_KEY.into(), &offset.into()).ok();
+
+    // Seed's route stays a JSON string, as it expects.
+    let state = if state.is_string() {
+        js_sys::JSON::stringify(&object).map(JsValue::from).unwrap_or(state)
+    } else {
+        object.into()
+    };
+    history.replace_state(&state, "").ok();
+}
+
+/// The offset saved in the current entry, if we've been to it before; eg
+/// before reloading the page.
+pub fn saved() -> Option<f64> {
+    let state = seed::window().history().ok()?.state().ok()?;
+    js_sys::Reflect::get(&state_object(&state), &OFFSET_KEY.into()).ok()?.as_f64()
+}
+
+/// Call when the route changes, before rendering the new page. Returns the
+/// offset to restore, for an entry we've returned to.
+pub fn navigated() -> Option<f64> {
+    // A save that's due would be for the page we've left.
+    if let Some(pending) = PENDING_SAVE.with(|p| p.replace(None)) {
+        seed::window().clear_timeout_with_handle(pending);
+    }
+
+    if POPPED.with(|p| p.replace(false)) {
+        saved()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,23 @@
+//! Projects built with Seed, from `showcase.toml`; `build.rs` checks them.
+
+/// A project, eg an app or a site.
+#[derive(Debug)]
+pub struct Project {
+    pub name: &'static str,
+    pub url: &'static str,
+    pub description: &'static str,
+    pub repository: Option<&'static str>,
+    pub tags: &'static [&'static str],
+    pub screenshot: Option<&'static str>,  // Eg "/public/showcase/seed-homepage.png".
+}
+
+// `PROJECTS`, in the order they're listed.
+include!(concat!(env!("OUT_DIR"), "/showcase.rs"));
+
+/// Every tag a project has, for filtering by.
+pub fn tags() -> Vec<&'static str> {
+    let mut tags: Vec<&str> = PROJECTS.iter().flat_map(|p| p.tags.iter().cloned()).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
@@ -0,0 +1,167 @@
+//! Golden snapshots of the whole page at each route: `view`'s output, serialized
+//! as normalized HTML and compared against `tests/snapshots/<name>.html`. These
+//! need a browser, for `raw!`; run with `cargo make test_h firefox`.
+//!
+//! After an intended change, run `cargo make bless firefox` to rewrite the
+//! files that differ, then review their diff. To add a route, add it to `ROUTES`
+//! and create an empty file for it; blessing fills it in. Until then, the route
+//! is only reported, so recording snapshots doesn't need every route at once.
+
+use seed::dom_types::{AtValue, CSSValue, El, Node};
+use wasm_bindgen_test::*;
+
+use crate::book::code_themes::DEFAULT_CODE_THEME;
+use crate::book::languages::DEFAULT_LANGUAGE;
+use crate::{navigate, routes, view, Model, Msg};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Name, route, and the checked-in snapshot.
+const ROUTES: &[(&str, &str, &str)] = &[
+    ("guide", "/guide", include_str!("../tests/snapshots/guide.html")),
+    ("guide-view", "/guide/view", include_str!("../tests/snapshots/guide-view.html")),
+    (
+        "guide-view-fragment",
+        "/guide/view#the-node-enum",
+        include_str!("../tests/snapshots/guide-view-fragment.html"),
+    ),
+    ("guide-fr", "/fr/guide/prereqs", include_str!("../tests/snapshots/guide-fr.html")),
+    ("examples", "/examples", include_str!("../tests/snapshots/examples.html")),
+    ("blog", "/blog", include_str!("../tests/snapshots/blog.html")),
+    (
+        "blog-post",
+        "/blog/orders-is-a-trait",
+        include_str!("../tests/snapshots/blog-post.html"),
+    ),
+    ("showcase", "/showcase", include_str!("../tests/snapshots/showcase.html")),
+    ("changelog", "/changelog", include_str!("../tests/snapshots/changelog.html")),
+];
+
+// `bless_snapshots.py` copies what's between these from the test output.
+const BEGIN: &str = "--- snapshot begin: ";
+const END: &str = "--- snapshot end";
+
+/// The model after following a link to `route`. Routes without a language
+/// prefix are shown in the default one, rather than the browser's.
+fn model_at(route: &str) -> Model {
+    let mut model = Model {
+        code_theme: DEFAULT_CODE_THEME.into(),
+        ..Model::default()
+    };
+
+    let msg = match routes(seed::Url::from(route.to_string())) {
+        Some(msg @ Msg::ChangeLanguage(..)) => msg,
+        Some(msg) => Msg::ChangeLanguage(DEFAULT_LANGUAGE.into(), Box::new(msg)),
+        None => panic!("No route for {}", route),
+    };
+    navigate(&mut model, msg);
+    // As `update` does, until the reader scrolls.
+    model.current_heading = model.fragment.clone();
+    model
+}
+
+fn escape(text: &str, quote: bool) -> String {
+    let text = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    if quote {
+        text.replace('"', "&quot;")
+    } else {
+        text
+    }
+}
+
+/// One element or text per line, indented by depth; attributes sorted, and
+/// whitespace-only text dropped. Links to this site don't depend on the port
+/// the tests are served from.
+fn serialize(node: &Node<Msg>, depth: usize, html: &mut String) {
+    let indent = "  ".repeat(depth);
+    match node {
+        Node::Element(el) => serialize_element(el, depth, html),
+        Node::Text(text) => {
+            let text = text.text.to_string();
+            if !text.trim().is_empty() {
+                html.push_str(&format!("{}{}\n", indent, escape(text.trim(), false)));
+            }
+        }
+        Node::Empty => (),
+    }
+}
+
+fn serialize_element(el: &El<Msg>, depth: usize, html: &mut String) {
+    let indent = "  ".repeat(depth);
+
+    let mut attrs: Vec<(String, String)> = el
+        .attrs
+        .vals
+        .iter()
+        .filter_map(|(at, value)| match value {
+            AtValue::Some(value) => Some((at.as_str().to_string(), value.clone())),
+            AtValue::None => Some((at.as_str().to_string(), String::new())),
+            AtValue::Ignored => None,
+        })
+        .collect();
+    let style: Vec<String> = el
+        .style
+        .vals
+        .iter()
+        .filter_map(|(key, value)| match value {
+            CSSValue::Some(value) => Some(format!("{}: {}", key.as_str(), value)),
+            CSSValue::Ignored => None,
+        })
+        .collect();
+    if !style.is_empty() {
+        attrs.push(("style".into(), style.join("; ")));
+    }
+    attrs.sort();
+
+    let origin = seed::window().location().origin().unwrap_or_default();
+    let attrs: String = attrs
+        .iter()
+        .map(|(name, value)| {
+            let value = value.replace(&origin, "{origin}");
+            format!(" {}=\"{}\"", name, escape(&value, true))
+        })
+        .collect();
+
+    let tag = el.tag.as_str();
+    html.push_str(&format!("{}<{}{}>\n", indent, tag, attrs));
+    for child in &el.children {
+        serialize(child, depth + 1, html);
+    }
+    html.push_str(&format!("{}</{}>\n", indent, tag));
+}
+
+fn render(route: &str) -> String {
+    let mut html = String::new();
+    serialize(&view(&model_at(route)), 0, &mut html);
+    html
+}
+
+#[wasm_bindgen_test]
+fn views_match_snapshots() {
+    let (mut unrecorded, mut mismatched) = (Vec::new(), Vec::new());
+    for (name, route, expected) in ROUTES {
+        let actual = render(route);
+        if actual == *expected {
+            continue;
+        }
+        // Shown with the failure, for blessing.
+        console_log!("{}{}\n{}{}", BEGIN, name, actual, END);
+        if expected.is_empty() {
+            unrecorded.push(*name);
+        } else {
+            mismatched.push(*name);
+        }
+    }
+
+    if !unrecorded.is_empty() {
+        console_log!(
+            "No snapshots recorded yet for: {}. Run `cargo make bless firefox`, and commit them.",
+            unrecorded.join(", ")
+        );
+    }
+    assert!(
+        mismatched.is_empty(),
+        "Views differ from their snapshots: {}. Run `cargo make bless firefox` if that's intended.",
+        mismatched.join(", ")
+    );
+}
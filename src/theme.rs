@@ -0,0 +1,219 @@
+//! Design tokens: the site's colours, spacing, sizes, type and breakpoints, in
+//! one place. `cargo make theme` publishes them as CSS custom properties, like
+//! `--color-accent`, in `theme.css`, which `style.css` uses; inline styles in
+//! views use the same tokens, through `Token::var`. To restyle the site, change
+//! `THEME`.
+//!
+//! Nothing here may depend on Seed or web-sys: `build/theme_css.rs`, which runs
+//! on the host, includes this file too.
+
+/// A named value, published as the custom property `--<name>`.
+#[derive(Clone, Copy, Debug)]
+pub struct Token {
+    pub name: &'static str,
+    pub value: &'static str,
+}
+
+impl Token {
+    const fn new(name: &'static str, value: &'static str) -> Self {
+        Self { name, value }
+    }
+
+    /// A reference to the token, for a style value; eg `var(--space-page)`.
+    pub fn var(&self) -> String {
+        format!("var(--{})", self.name)
+    }
+}
+
+/// A struct of tokens, with a `tokens` method listing every field, so a new
+/// one is published without listing it again.
+macro_rules! token_group {
+    ($(#[$meta:meta])* pub struct $name:ident { $(pub $field:ident: Token,)* }) => {
+        $(#[$meta])*
+        pub struct $name {
+            $(pub $field: Token,)*
+        }
+
+        impl $name {
+            #[allow(dead_code)]  // Only `build/theme_css.rs` publishes tokens.
+            fn tokens(&self) -> Vec<Token> {
+                vec![$(self.$field),*]
+            }
+        }
+    };
+}
+
+token_group! {
+    #[derive(Debug)]
+    pub struct Colors {
+        pub text: Token,
+        pub muted: Token,
+        pub faint: Token,  // Hints, and separators.
+        pub accent: Token,
+        pub on_accent: Token,  // Text on `accent`, or `muted`.
+        pub header: Token,  // Also the guide menu.
+        pub highlight: Token,  // Hovered and picked items.
+        pub surface: Token,  // The page, and what's over it, like dialogs.
+        pub paper: Token,  // Code, and cards.
+        pub background: Token,  // Behind content.
+        pub button: Token,
+        pub border: Token,  // Form fields.
+        pub key_border: Token,  // Keys in the shortcuts list.
+        pub shadow: Token,  // Also dims the page behind dialogs.
+        pub link: Token,
+        pub link_hover: Token,
+        // Callouts in the guide.
+        pub tip: Token,
+        pub warning: Token,
+        pub danger: Token,
+    }
+}
+
+token_group! {
+    #[derive(Debug)]
+    pub struct Spacing {
+        pub xs: Token,
+        pub tight: Token,  // Between small inline items, like tags and keys.
+        pub sm: Token,
+        pub md: Token,
+        pub inset: Token,  // Inside code blocks and boxed notes.
+        pub field: Token,  // Inside form fields; vertical and horizontal.
+        pub chip: Token,  // Inside tags and badges; vertical and horizontal.
+        pub lg: Token,
+        pub xl: Token,
+        pub card: Token,  // Inside cards, and between their parts.
+        pub post: Token,  // Between posts in the blog's index.
+        pub section: Token,
+        pub heading: Token,  // Above the guide's headings.
+        pub page: Token,  // Around page content.
+    }
+}
+
+token_group! {
+    #[derive(Debug)]
+    pub struct Sizes {
+        pub menu_width: Token,  // The guide's section menu; also buttons.
+        pub menu_item_height: Token,
+        pub title_row_height: Token,
+        pub field_width: Token,  // Text inputs.
+        pub card_min_width: Token,  // Example and showcase cards.
+        pub dialog_width: Token,  // Most a dialog grows to.
+        pub shortcut_width: Token,  // Keys in the shortcuts list.
+    }
+}
+
+token_group! {
+    #[derive(Debug)]
+    pub struct Typography {
+        pub font_family: Token,
+        pub body_size: Token,
+        pub code_size: Token,
+        pub nav_size: Token,
+    }
+}
+
+/// Widths at and below which the layout tightens; media queries can't use
+/// custom properties, so these are applied in `Theme::stylesheet`.
+#[derive(Debug)]
+pub struct Breakpoints {
+    pub narrow: u32,  // px
+}
+
+#[derive(Debug)]
+pub struct Theme {
+    pub colors: Colors,
+    pub spacing: Spacing,
+    pub sizes: Sizes,
+    pub typography: Typography,
+    pub breakpoints: Breakpoints,
+}
+
+pub const THEME: Theme = Theme {
+    colors: Colors {
+        text: Token::new("color-text", "black"),
+        muted: Token::new("color-muted", "#687864"),
+        faint: Token::new("color-faint", "#666"),
+        accent: Token::new("color-accent", "#31708e"),
+        on_accent: Token::new("color-on-accent", "white"),
+        header: Token::new("color-header", "#d9efdd"),
+        highlight: Token::new("color-highlight", "#c4f4d7"),
+        surface: Token::new("color-surface", "white"),
+        paper: Token::new("color-paper", "#fffbf4"),
+        background: Token::new("color-background", "#ebf5fc"),
+        button: Token::new("color-button", "#c4ddd2"),
+        border: Token::new("color-border", "black"),
+        key_border: Token::new("color-key-border", "#aaa"),
+        shadow: Token::new("color-shadow", "rgba(0, 0, 0, 0.3)"),
+        link: Token::new("color-link", "#7169aa"),
+        link_hover: Token::new("color-link-hover", "#8687d8"),
+        tip: Token::new("color-tip", "#3c8d40"),
+        warning: Token::new("color-warning", "#c77c02"),
+        danger: Token::new("color-danger", "#b3261e"),
+    },
+    spacing: Spacing {
+        xs: Token::new("space-xs", "4px"),
+        tight: Token::new("space-tight", "6px"),
+        sm: Token::new("space-sm", "8px"),
+        md: Token::new("space-md", "10px"),
+        inset: Token::new("space-inset", "14px"),
+        field: Token::new("space-field", "5px 12px"),
+        chip: Token::new("space-chip", "2px 8px"),
+        lg: Token::new("space-lg", "20px"),
+        xl: Token::new("space-xl", "30px"),
+        card: Token::new("space-card", "16px"),
+        post: Token::new("space-post", "32px"),
+        section: Token::new("space-section", "50px"),
+        heading: Token::new("space-heading", "60px"),
+        page: Token::new("space-page", "80px"),
+    },
+    sizes: Sizes {
+        menu_width: Token::new("size-menu-width", "200px"),
+        menu_item_height: Token::new("size-menu-item-height", "40px"),
+        title_row_height: Token::new("size-title-row-height", "160px"),
+        field_width: Token::new("size-field-width", "320px"),
+        card_min_width: Token::new("size-card-min-width", "280px"),
+        dialog_width: Token::new("size-dialog-width", "600px"),
+        shortcut_width: Token::new("size-shortcut-width", "140px"),
+    },
+    typography: Typography {
+        font_family: Token::new("font-family", "'Karla', sans-serif"),
+        body_size: Token::new("font-size-body", "16px"),
+        code_size: Token::new("font-size-code", "14px"),
+        nav_size: Token::new("font-size-nav", "1.2em"),
+    },
+    breakpoints: Breakpoints { narrow: 800 },
+};
+
+#[allow(dead_code)]  // Only `build/theme_css.rs` publishes tokens.
+impl Theme {
+    fn tokens(&self) -> Vec<Token> {
+        [
+            self.colors.tokens(),
+            self.spacing.tokens(),
+            self.sizes.tokens(),
+            self.typography.tokens(),
+        ]
+        .concat()
+    }
+
+    /// Every token as a custom property on the root element, and narrow
+    /// screens' overrides.
+    pub fn stylesheet(&self) -> String {
+        let properties: String = self
+            .tokens()
+            .iter()
+            .map(|t| format!("--{}: {};", t.name, t.value))
+            .collect();
+        let s = &self.spacing;
+
+        format!(
+            ":root {{{}}}\n@media (max-width: {}px) {{:root {{--{}: {}; --{}: {};}}}}",
+            properties,
+            self.breakpoints.narrow,
+            s.page.name,
+            s.lg.value,
+            s.section.name,
+            s.lg.value,
+        )
+    }
+}
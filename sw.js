@@ -0,0 +1,63 @@
+// Precaches the files listed in `asset-manifest.json` (generated by
+// `make_manifest.py`), so the homepage works offline. Registered from
+// `render()` in release builds.
+
+// The manifest's version, stamped in by `make_manifest.py`. Since this file
+// changes whenever the site does, browsers install the new worker, which
+// precaches the new files.
+const VERSION = '6346125c571f22ec';
+const CACHE_PREFIX = 'seed-homepage-';
+const CACHE_NAME = CACHE_PREFIX + VERSION;
+const FONT_HOSTS = ['fonts.googleapis.com', 'fonts.gstatic.com'];
+
+self.addEventListener('install', event => {
+    event.waitUntil((async () => {
+        const response = await fetch('/asset-manifest.json', {cache: 'no-store'});
+        const {files} = await response.json();
+        const cache = await caches.open(CACHE_NAME);
+        // Past the HTTP cache, so a stale file isn't precached as the new one.
+        await cache.addAll(files.map(file => new Request(file, {cache: 'reload'})));
+        await self.skipWaiting();
+    })());
+});
+
+// Remove caches from older versions of the site.
+self.addEventListener('activate', event => {
+    event.waitUntil((async () => {
+        const names = await caches.keys();
+        await Promise.all(names
+            .filter(n => n.startsWith(CACHE_PREFIX) && n !== CACHE_NAME)
+            .map(n => caches.delete(n)));
+        await self.clients.claim();
+    })());
+});
+
+self.addEventListener('fetch', event => {
+    const request = event.request;
+    if (request.method !== 'GET') {
+        return;
+    }
+
+    // All routes render from `index.html`, as with `_redirects` on the server.
+    if (request.mode === 'navigate') {
+        event.respondWith(
+            fetch(request).catch(() => caches.match('/index.html'))
+        );
+        return;
+    }
+
+    event.respondWith((async () => {
+        const cached = await caches.match(request);
+        if (cached) {
+            return cached;
+        }
+
+        const response = await fetch(request);
+        // Font files are only known once the font stylesheet has loaded.
+        if (FONT_HOSTS.includes(new URL(request.url).hostname)) {
+            const cache = await caches.open(CACHE_NAME);
+            await cache.put(request, response.clone());
+        }
+        return response;
+    })());
+});
@@ -0,0 +1,50 @@
+//! Checks that every UI text catalog in `locales/` defines the same message ids.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+#[path = "../src/book/languages.rs"]
+mod languages;
+
+use languages::{DEFAULT_LANGUAGE, LANGUAGES};
+
+/// Message ids defined in a catalog; lines like `nav-guide = Guide`.
+fn ids(language: &str) -> BTreeSet<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("locales")
+        .join(format!("{}.ftl", language));
+    let source = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing a catalog for `{}`: {:?}", language, path));
+
+    source
+        .lines()
+        .filter(|line| !line.starts_with(|c: char| c == '#' || c == '}' || c.is_whitespace()))
+        .filter_map(|line| Some(line[..line.find('=')?].trim().to_string()))
+        .collect()
+}
+
+#[test]
+fn catalogs_have_all_keys() {
+    let expected = ids(DEFAULT_LANGUAGE);
+
+    for (language, _) in LANGUAGES {
+        let found = ids(language);
+        let missing: Vec<_> = expected.difference(&found).collect();
+        let extra: Vec<_> = found.difference(&expected).collect();
+
+        assert!(
+            missing.is_empty(),
+            "`locales/{}.ftl` is missing: {:?}",
+            language,
+            missing
+        );
+        assert!(
+            extra.is_empty(),
+            "`locales/{}.ftl` has ids not in `{}.ftl`: {:?}",
+            language,
+            DEFAULT_LANGUAGE,
+            extra
+        );
+    }
+}
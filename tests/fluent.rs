@@ -0,0 +1,116 @@
+//! The catalog syntax `src/fluent.rs` understands, and how messages are formatted
+//! from it.
+
+#[path = "../src/fluent.rs"]
+mod fluent;
+
+use fluent::{Arg, Messages};
+
+const EN: &str = "
+# A comment, and a blank line, are skipped.
+
+greeting = Hello, { $name }!
+posts = { $count ->
+    [0] No posts
+    [one] One post
+   *[other] { $count } posts
+}
+status = { $state ->
+    [draft] Not published yet
+   *[other] Published
+} on { $date }
+english-only = Only in English
+";
+
+const FR: &str = "
+greeting = Bonjour, { $name } !
+posts = { $count ->
+    [one] { $count } article
+   *[other] { $count } articles
+}
+";
+
+fn en() -> Messages {
+    Messages::from_catalogs("en", EN, EN)
+}
+
+fn fr() -> Messages {
+    Messages::from_catalogs("fr", FR, EN)
+}
+
+#[test]
+fn placeables() {
+    assert_eq!(
+        en().format("greeting", &[("name", Arg::Str("Seed"))]),
+        "Hello, Seed!"
+    );
+}
+
+#[test]
+fn missing_args_are_shown() {
+    assert_eq!(en().get("greeting"), "Hello, {$name}!");
+}
+
+#[test]
+fn select_picks_a_matching_variant() {
+    let status = |state| {
+        en().format(
+            "status",
+            &[("state", Arg::Str(state)), ("date", Arg::Str("today"))],
+        )
+    };
+    assert_eq!(status("draft"), "Not published yet on today");
+}
+
+#[test]
+fn select_falls_back_to_the_default_variant() {
+    let status = en().format(
+        "status",
+        &[("state", Arg::Str("live")), ("date", Arg::Str("today"))],
+    );
+    assert_eq!(status, "Published on today");
+    assert_eq!(en().format("posts", &[]), "{$count} posts");
+}
+
+#[test]
+fn exact_numbers_match_before_plural_categories() {
+    assert_eq!(en().format("posts", &[("count", Arg::Num(0))]), "No posts");
+    assert_eq!(en().format("posts", &[("count", Arg::Num(1))]), "One post");
+    assert_eq!(en().format("posts", &[("count", Arg::Num(2))]), "2 posts");
+}
+
+#[test]
+fn french_zero_and_one_are_singular() {
+    assert_eq!(fr().format("posts", &[("count", Arg::Num(0))]), "0 article");
+    assert_eq!(fr().format("posts", &[("count", Arg::Num(1))]), "1 article");
+    assert_eq!(
+        fr().format("posts", &[("count", Arg::Num(2))]),
+        "2 articles"
+    );
+}
+
+#[test]
+fn site_catalogs_pluralize() {
+    let en = include_str!("../locales/en.ftl");
+    let count = |language, source, n| {
+        Messages::from_catalogs(language, source, en)
+            .format("examples-count", &[("count", Arg::Num(n))])
+    };
+
+    assert_eq!(count("en", en, 1), "1 example");
+    assert_eq!(count("en", en, 3), "3 examples");
+    assert_eq!(
+        count("fr", include_str!("../locales/fr.ftl"), 1),
+        "1 exemple"
+    );
+    assert_eq!(
+        count("fr", include_str!("../locales/fr.ftl"), 3),
+        "3 exemples"
+    );
+}
+
+#[test]
+fn missing_translations_fall_back() {
+    assert_eq!(fr().get("english-only"), "Only in English");
+    assert_eq!(fr().get("nonexistent"), "nonexistent");
+}
@@ -0,0 +1,14 @@
+//! Compiles every ```rust block under `markdown/`, in the guide, its translations
+//! and the blog, against the Seed we build with, so examples that have drifted
+//! from its API fail the build. `build.rs` collects them; lines starting with
+//! `# ` are compiled but hidden on the site, for setup like `# struct Model;`.
+//! Mark code that can't compile alone ```rust,ignore.
+
+#[macro_use]
+extern crate seed;
+
+include!(concat!(env!("OUT_DIR"), "/guide_examples.rs"));
+
+/// Compiling is the check; this names it in the test output.
+#[test]
+fn guide_examples_compile() {}
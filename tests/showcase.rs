@@ -0,0 +1,154 @@
+//! Checks that mistakes in `showcase.toml` are reported, with their lines.
+
+use std::collections::HashSet;
+
+#[path = "../build/showcase.rs"]
+#[allow(dead_code)]
+mod showcase;
+
+use showcase::{check_project, parse_showcase};
+
+const PROJECT: &str = r#"
+[[project]]
+name = "Seed homepage"
+url = "https://seed-rs.org"
+description = "This site."
+"#;
+
+/// The errors in a showcase, after parsing and checking each project.
+fn errors(source: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+    let mut names = HashSet::new();
+    for table in parse_showcase(source, &mut errors) {
+        check_project(&table, &mut names, &mut errors);
+    }
+    errors
+}
+
+#[test]
+fn valid_project() {
+    let mut errors = Vec::new();
+    let tables = parse_showcase(
+        &format!("{}tags = [\"docs\", \"i18n\"]\n", PROJECT),
+        &mut errors,
+    );
+
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].line, 2);
+    assert_eq!(tables[0].str("name"), Some("Seed homepage"));
+    assert_eq!(tables[0].strs("tags"), ["docs", "i18n"]);
+}
+
+#[test]
+fn the_site_showcase_is_valid() {
+    let source = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/showcase.toml"))
+        .expect("Problem reading showcase.toml");
+    assert_eq!(errors(&source), Vec::<String>::new());
+}
+
+#[test]
+fn syntax_errors() {
+    let errors = errors("[[project]]\nname = \"Unclosed\nurl = \"https://example.com\"\n");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("2: "), "{}", errors[0]);
+    assert!(!errors[0].contains(" at line "), "{}", errors[0]);
+}
+
+#[test]
+fn duplicate_keys() {
+    let errors = errors(&format!("{}name = \"Again\"\n", PROJECT));
+
+    // `toml` reports it at the table.
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("2: "), "{}", errors[0]);
+    assert!(errors[0].contains("duplicate key: `name`"), "{}", errors[0]);
+}
+
+#[test]
+fn keys_outside_projects() {
+    let source = format!(
+        "title = \"Showcase\"\n{}\n[links]\nhome = \"https://seed-rs.org\"\n",
+        PROJECT
+    );
+
+    assert_eq!(
+        errors(&source),
+        [
+            "8: `links` is outside a `[[project]]` table",
+            "1: `title` is outside a `[[project]]` table",
+        ]
+    );
+    assert_eq!(
+        errors("[project]\nname = \"Seed homepage\"\n"),
+        ["1: projects should be `[[project]]` tables"]
+    );
+}
+
+#[test]
+fn unknown_and_missing_keys() {
+    assert_eq!(
+        errors("\n[[project]]\nname = \"Seed homepage\"\nwebsite = \"https://seed-rs.org\"\n"),
+        [
+            "4: unknown key `website`; expected one of name, url, description, repository, \
+             tags, screenshot",
+            "2: project is missing `url`",
+            "2: project is missing `description`",
+        ]
+    );
+}
+
+#[test]
+fn bad_values() {
+    let source = format!(
+        "{}repository = \"github.com/seed-rs/seed\"\ntags = \"docs\"\nscreenshot = 3\n",
+        PROJECT
+    );
+
+    assert_eq!(
+        errors(&source),
+        [
+            "6: `repository` should be a full URL, like https://…",
+            "8: `screenshot` should be a string",
+            "7: `tags` should be a list, like [\"games\"]",
+        ]
+    );
+    assert_eq!(
+        errors(&PROJECT.replace("This site.", " ")),
+        ["5: `description` is empty"]
+    );
+}
+
+#[test]
+fn bad_tags() {
+    assert_eq!(
+        errors(&format!("{}tags = [\"Docs\", 2, \"i18n\"]\n", PROJECT)),
+        [
+            "6: tag \"Docs\" should be lowercase letters, digits and hyphens",
+            "6: tag 2 should be a string",
+        ]
+    );
+}
+
+#[test]
+fn missing_screenshots() {
+    assert_eq!(
+        errors(&format!("{}screenshot = \"missing.png\"\n", PROJECT)),
+        ["6: no screenshot at public/showcase/missing.png"]
+    );
+}
+
+#[test]
+fn duplicate_names() {
+    let source = format!(
+        "{}{}",
+        PROJECT,
+        PROJECT.replace("Seed homepage", "seed Homepage")
+    );
+
+    assert_eq!(
+        errors(&source),
+        ["7: there's already a project named \"seed Homepage\""]
+    );
+}
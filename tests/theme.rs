@@ -0,0 +1,21 @@
+//! Checks that `theme.css` has the tokens in `src/theme.rs`, since nothing
+//! rewrites it during a plain `cargo build`.
+
+use std::fs;
+use std::path::Path;
+
+#[path = "../src/theme.rs"]
+#[allow(dead_code)]
+mod theme;
+
+#[test]
+fn theme_css_is_current() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("theme.css");
+    let css = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Problem reading {:?}: {}", path, e));
+
+    assert!(
+        css.contains(&theme::THEME.stylesheet()),
+        "theme.css is out of date; run `cargo make theme`, and commit it."
+    );
+}
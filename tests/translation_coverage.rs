@@ -0,0 +1,69 @@
+//! Reports how much of the guide each language covers. Run with
+//! `cargo test --test translation_coverage -- --nocapture` to see the table.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+#[path = "../src/book/languages.rs"]
+mod languages;
+
+use languages::{DEFAULT_LANGUAGE, LANGUAGES};
+
+/// The chapter names (file stems) of the markdown files in a directory.
+fn chapters(dir: &Path) -> BTreeSet<String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return BTreeSet::new(), // No translations yet.
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension() == Some(OsStr::new("md")))
+        .filter_map(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+        .collect()
+}
+
+#[test]
+fn translation_coverage() {
+    let markdown = Path::new(env!("CARGO_MANIFEST_DIR")).join("markdown");
+    let english = chapters(&markdown);
+
+    let translations: Vec<_> = LANGUAGES
+        .iter()
+        .filter(|(code, _)| *code != DEFAULT_LANGUAGE)
+        .map(|(code, _)| (*code, chapters(&markdown.join(code))))
+        .collect();
+
+    for (code, translated) in &translations {
+        let orphans: Vec<_> = translated.difference(&english).collect();
+        assert!(
+            orphans.is_empty(),
+            "`markdown/{}` has chapters with no English original: {:?}",
+            code,
+            orphans
+        );
+    }
+
+    print!("{:<24}", "chapter");
+    for (code, _) in &translations {
+        print!("{:>6}", code);
+    }
+    println!();
+
+    for chapter in &english {
+        print!("{:<24}", chapter);
+        for (_, translated) in &translations {
+            print!("{:>6}", if translated.contains(chapter) { "yes" } else { "-" });
+        }
+        println!();
+    }
+
+    print!("{:<24}", "coverage");
+    for (_, translated) in &translations {
+        print!("{:>5}%", 100 * translated.len() / english.len());
+    }
+    println!();
+}